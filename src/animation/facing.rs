@@ -0,0 +1,119 @@
+use crate::animation::PostTransformSystems;
+use crate::animation::backup::Backup;
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<Facing>();
+}
+
+/// The direction an entity is facing, applied on top of its [`Transform`] and [`Sprite`].
+#[derive(Component, Reflect, Copy, Clone, Debug)]
+#[reflect(Component)]
+pub struct Facing {
+    pub direction: Dir2,
+    pub mode: FacingMode,
+}
+
+impl Configure for Facing {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_observer(backup_rotated_transform);
+        app.add_systems(
+            PostUpdate,
+            apply_facing.in_set(PostTransformSystems::ApplyFacing),
+        );
+    }
+}
+
+impl Facing {
+    pub fn new(direction: Dir2, mode: FacingMode) -> Self {
+        Self { direction, mode }
+    }
+
+    /// Whether the entity is facing left, for [`FacingMode::Mirror`].
+    pub fn is_left(self) -> bool {
+        self.direction.x < 0.0
+    }
+}
+
+/// How [`Facing`] is displayed.
+#[derive(Reflect, Copy, Clone, Default, Eq, PartialEq, Debug)]
+pub enum FacingMode {
+    /// Flip sprites horizontally when facing left, and mirror the translation of child
+    /// entities with [`Backup<Transform>`] (e.g. with an [`Offset`](super::offset::Offset)).
+    #[default]
+    Mirror,
+    /// Rotate to face the direction, as a rotation from [`Dir2::X`].
+    ///
+    /// Not supported on rigid bodies, since physics owns their [`Transform`]. Put [`Facing`]
+    /// on a visual child entity instead.
+    Rotate,
+}
+
+/// Back up the [`Transform`] of entities that rotate to face, so the rotation is
+/// restored each frame instead of accumulating.
+fn backup_rotated_transform(
+    trigger: Trigger<OnInsert, Facing>,
+    mut commands: Commands,
+    facing_query: Query<(&Facing, Has<RigidBody>)>,
+) {
+    let target = r!(trigger.get_target());
+    let (facing, is_rigid_body) = rq!(facing_query.get(target));
+    rq!(facing.mode == FacingMode::Rotate);
+    if is_rigid_body {
+        warn!("Rotating to face is not supported on rigid body {target}");
+        return;
+    }
+    commands
+        .entity(target)
+        .insert_if_new(Backup::<Transform>::default());
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn apply_facing(
+    mut facing_query: Query<(
+        &Facing,
+        &mut Transform,
+        Has<Backup<Transform>>,
+        Option<&mut Sprite>,
+        Option<&Children>,
+    )>,
+    mut child_query: Query<
+        (
+            Option<&mut Transform>,
+            Has<Backup<Transform>>,
+            Option<&mut Sprite>,
+        ),
+        Without<Facing>,
+    >,
+) {
+    for (&facing, mut transform, has_backup, sprite, children) in &mut facing_query {
+        match facing.mode {
+            FacingMode::Rotate => {
+                // Only rotate transforms that will be restored from backup next frame.
+                cq!(has_backup);
+                transform.rotation = facing.direction.to_quat() * transform.rotation;
+            },
+            FacingMode::Mirror => {
+                let is_left = facing.is_left();
+                if let Some(mut sprite) = sprite {
+                    sprite.flip_x = is_left;
+                }
+
+                for &child in children.into_iter().flatten() {
+                    let (child_transform, has_backup, child_sprite) =
+                        c!(child_query.get_mut(child));
+                    if let Some(mut sprite) = child_sprite {
+                        sprite.flip_x = is_left;
+                    }
+                    // Only mirror transforms that will be restored from backup next frame.
+                    if is_left && has_backup {
+                        if let Some(mut child_transform) = child_transform {
+                            child_transform.translation.x *= -1.0;
+                        }
+                    }
+                }
+            },
+        }
+    }
+}
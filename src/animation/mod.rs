@@ -1,5 +1,9 @@
 pub mod backup;
+pub mod facing;
 pub mod offset;
+pub mod particle;
+pub mod sprite;
+pub mod tween;
 
 use bevy::ui::UiSystem;
 
@@ -8,7 +12,14 @@ use crate::prelude::*;
 pub(super) fn plugin(app: &mut App) {
     app.configure::<(SaveBackupSystems, PostTransformSystems, PostColorSystems)>();
 
-    app.add_plugins((backup::plugin, offset::plugin));
+    app.add_plugins((
+        backup::plugin,
+        facing::plugin,
+        offset::plugin,
+        particle::plugin,
+        sprite::plugin,
+        tween::plugin,
+    ));
 }
 
 #[derive(SystemSet, Clone, Eq, PartialEq, Hash, Debug)]
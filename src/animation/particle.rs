@@ -0,0 +1,350 @@
+use rand::rngs::StdRng;
+
+use crate::prelude::*;
+use crate::screen::Screen;
+use crate::theme::color::ThemeColorList;
+use crate::theme::color::ThemeConfig;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<(
+        ConfigHandle<ParticleConfig>,
+        ParticleRng,
+        ParticleEmitter,
+        Particle,
+    )>();
+}
+
+#[derive(Asset, Reflect, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ParticleConfig {
+    pub presets: HashMap<String, ParticlePreset>,
+}
+
+impl Config for ParticleConfig {
+    const FILE: &'static str = "particle.ron";
+}
+
+/// The behavior of a [`ParticleEmitter`] and the particles it emits.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ParticlePreset {
+    pub emission: ParticleEmission,
+    /// The range of particle lifetimes, in seconds.
+    pub lifetime: (f32, f32),
+    /// The range of initial particle speeds.
+    pub speed: (f32, f32),
+    /// The initial direction of particles, in radians counterclockwise from +X.
+    #[serde(default)]
+    pub angle: f32,
+    /// The range of directions around `angle`, in radians (`TAU` for all directions).
+    pub spread: f32,
+    /// The constant acceleration applied to particles.
+    #[serde(default)]
+    pub gravity: Vec2,
+    /// The colors to blend between over a particle's lifetime.
+    pub colors: Vec<ThemeColor>,
+    /// The alpha at the start and end of a particle's lifetime.
+    #[serde(default = "one_to_zero")]
+    pub alpha: (f32, f32),
+    /// The size at the start and end of a particle's lifetime.
+    pub size: (f32, f32),
+}
+
+fn one_to_zero() -> (f32, f32) {
+    (1.0, 0.0)
+}
+
+impl ParticlePreset {
+    /// Create a new particle with randomized lifetime and velocity.
+    pub fn particle(&self, rng: &mut impl Rng) -> Particle {
+        let lifetime = self
+            .lifetime
+            .0
+            .lerp(self.lifetime.1, rng.gen_range(0.0..=1.0));
+        let speed = self.speed.0.lerp(self.speed.1, rng.gen_range(0.0..=1.0));
+        let angle = self.angle + self.spread * rng.gen_range(-0.5..=0.5);
+        Particle {
+            velocity: speed * Vec2::from_angle(angle),
+            age: 0.0,
+            lifetime: lifetime.max(f32::EPSILON),
+        }
+    }
+
+    /// The color at a point in a particle's lifetime, from `0.0` to `1.0`.
+    pub fn color(&self, palette: &ThemeColorList, t: f32) -> Color {
+        let alpha = self.alpha.0.lerp(self.alpha.1, t);
+        let color = match self.colors.len() {
+            0 => return Color::WHITE.with_alpha(alpha),
+            1 => palette[self.colors[0]],
+            n => {
+                let x = t.clamp(0.0, 1.0) * (n - 1) as f32;
+                let i = (x as usize).min(n - 2);
+                let start = LinearRgba::from(palette[self.colors[i]]);
+                let end = LinearRgba::from(palette[self.colors[i + 1]]);
+                start.mix(&end, x - i as f32).into()
+            },
+        };
+
+        color.with_alpha(color.alpha() * alpha)
+    }
+
+    /// The size at a point in a particle's lifetime, from `0.0` to `1.0`.
+    pub fn size(&self, t: f32) -> f32 {
+        self.size.0.lerp(self.size.1, t)
+    }
+}
+
+/// How a [`ParticleEmitter`] emits particles.
+#[derive(Reflect, Serialize, Deserialize, Copy, Clone, Debug)]
+pub enum ParticleEmission {
+    /// Emit a number of particles all at once.
+    Burst(u32),
+    /// Emit particles at a rate per second.
+    Continuous(f32),
+}
+
+/// The source of randomness for particles.
+#[derive(Resource, Reflect)]
+#[reflect(Resource)]
+pub struct ParticleRng(#[reflect(ignore, default = "entropy_rng")] pub StdRng);
+
+impl Configure for ParticleRng {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+    }
+}
+
+impl Default for ParticleRng {
+    fn default() -> Self {
+        Self(entropy_rng())
+    }
+}
+
+fn entropy_rng() -> StdRng {
+    StdRng::from_entropy()
+}
+
+/// Emits particles from a [`ParticlePreset`] in [`ParticleConfig`].
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+#[require(Transform)]
+pub struct ParticleEmitter {
+    pub preset: String,
+    /// Whether the emitter is emitting.
+    ///
+    /// Burst emitters turn this off after emitting their burst.
+    pub active: bool,
+    /// Whether to despawn the emitter once it's no longer active.
+    pub despawn_when_inactive: bool,
+    /// The fractional number of particles left over from previous frames.
+    debt: f32,
+}
+
+impl Configure for ParticleEmitter {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            Update,
+            (
+                state!(Screen::Title | Screen::Loading)
+                    .on_update(ParticleConfig::progress.track_progress::<BevyState<Screen>>()),
+                emit_particles
+                    .in_set(UpdateSystems::Update)
+                    .in_set(PausableSystems),
+            ),
+        );
+    }
+}
+
+impl ParticleEmitter {
+    pub fn new(preset: impl Into<String>) -> Self {
+        Self {
+            preset: preset.into(),
+            active: true,
+            despawn_when_inactive: false,
+            debt: 0.0,
+        }
+    }
+
+    /// Advance the emitter, returning the number of particles to emit.
+    pub fn tick(&mut self, emission: ParticleEmission, dt: f32) -> u32 {
+        if !self.active {
+            return 0;
+        }
+
+        match emission {
+            ParticleEmission::Burst(count) => {
+                self.active = false;
+                count
+            },
+            ParticleEmission::Continuous(rate) => {
+                self.debt += rate * dt;
+                let count = self.debt.floor();
+                self.debt -= count;
+                count as u32
+            },
+        }
+    }
+}
+
+/// A one-shot burst of particles at a position.
+pub fn particle_burst(preset: impl Into<String>, position: Vec2) -> impl Bundle {
+    (
+        Name::new("ParticleBurst"),
+        ParticleEmitter {
+            despawn_when_inactive: true,
+            ..ParticleEmitter::new(preset)
+        },
+        Transform::from_translation(position.extend(0.0)),
+        DespawnOnExitState::<Screen>::default(),
+    )
+}
+
+/// The z-coordinate for particles.
+const PARTICLE_Z: f32 = 2.0;
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn emit_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: ConfigRef<ParticleConfig>,
+    mut rng: ResMut<ParticleRng>,
+    mut emitter_query: Query<(Entity, &GlobalTransform, &mut ParticleEmitter)>,
+) {
+    let config = r!(config.get());
+    for (entity, gt, mut emitter) in &mut emitter_query {
+        let preset = cq!(config.presets.get(&emitter.preset));
+        let count = emitter.tick(preset.emission, time.delta_secs());
+
+        let position = gt.translation().xy().extend(PARTICLE_Z);
+        for _ in 0..count {
+            commands.spawn((
+                Name::new("Particle"),
+                preset.particle(&mut rng.0),
+                ParticleOf(emitter.preset.clone()),
+                Transform::from_translation(position),
+                Sprite::from_color(Color::NONE, Vec2::splat(preset.size(0.0))),
+                DespawnOnExitState::<Screen>::default(),
+            ));
+        }
+
+        if !emitter.active && emitter.despawn_when_inactive {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// A particle emitted by a [`ParticleEmitter`].
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct Particle {
+    pub velocity: Vec2,
+    /// The time since the particle was emitted, in seconds.
+    pub age: f32,
+    /// The time until the particle despawns, in seconds.
+    pub lifetime: f32,
+}
+
+impl Configure for Particle {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.register_type::<ParticleOf>();
+        app.add_systems(
+            Update,
+            update_particles
+                .in_set(UpdateSystems::Update)
+                .in_set(PausableSystems)
+                .after(emit_particles),
+        );
+    }
+}
+
+impl Particle {
+    /// Advance the particle, returning its displacement.
+    pub fn step(&mut self, gravity: Vec2, dt: f32) -> Vec2 {
+        self.age += dt;
+        self.velocity += gravity * dt;
+        self.velocity * dt
+    }
+
+    /// The fraction of the particle's lifetime that has passed, from `0.0` to `1.0`.
+    pub fn progress(&self) -> f32 {
+        (self.age / self.lifetime).clamp(0.0, 1.0)
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.age >= self.lifetime
+    }
+}
+
+/// The name of the [`ParticlePreset`] a [`Particle`] was emitted from.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct ParticleOf(pub String);
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn update_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: ConfigRef<ParticleConfig>,
+    theme_config: ConfigRef<ThemeConfig>,
+    mut particle_query: Query<(
+        Entity,
+        &mut Particle,
+        &ParticleOf,
+        &mut Transform,
+        &mut Sprite,
+    )>,
+) {
+    let config = r!(config.get());
+    let palette = r!(theme_config.get().map(|x| &x.colors));
+    let dt = time.delta_secs();
+    for (entity, mut particle, preset, mut transform, mut sprite) in &mut particle_query {
+        let preset = cq!(config.presets.get(&preset.0));
+        transform.translation += particle.step(preset.gravity, dt).extend(0.0);
+        if particle.is_expired() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let t = particle.progress();
+        sprite.color = preset.color(palette, t);
+        sprite.custom_size = Some(Vec2::splat(preset.size(t)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_emission() {
+        let mut burst = ParticleEmitter::new("");
+        assert_eq!(burst.tick(ParticleEmission::Burst(12), 0.1), 12);
+        assert!(!burst.active);
+        assert_eq!(burst.tick(ParticleEmission::Burst(12), 0.1), 0);
+
+        let mut continuous = ParticleEmitter::new("");
+        let total = (0..10)
+            .map(|_| continuous.tick(ParticleEmission::Continuous(10.0), 0.25))
+            .sum::<u32>();
+        assert_eq!(total, 25);
+        assert!(continuous.active);
+    }
+
+    #[test]
+    fn test_particle_lifetime() {
+        let mut particle = Particle {
+            velocity: Vec2::X,
+            age: 0.0,
+            lifetime: 1.0,
+        };
+        let displacement = particle.step(Vec2::NEG_Y, 0.5);
+        assert_eq!(displacement, vec2(0.5, -0.25));
+        assert_eq!(particle.progress(), 0.5);
+        assert!(!particle.is_expired());
+        particle.step(Vec2::NEG_Y, 0.5);
+        assert!(particle.is_expired());
+    }
+}
@@ -0,0 +1,383 @@
+use bevy::ecs::component::Mutable;
+
+use crate::prelude::*;
+use crate::screen::Screen;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<(
+        ConfigHandle<SpriteAnimationConfig>,
+        SpriteSheets,
+        SpriteAnimation,
+        SpriteFrameEvent,
+    )>();
+}
+
+#[derive(Asset, Reflect, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SpriteAnimationConfig {
+    pub sheets: HashMap<String, SpriteSheetConfig>,
+}
+
+impl Config for SpriteAnimationConfig {
+    const FILE: &'static str = "sprite_animation.ron";
+
+    fn on_load(&self, world: &mut World) {
+        let asset_server = r!(world.get_resource::<AssetServer>()).clone();
+        let mut layouts = r!(world.get_resource_mut::<Assets<TextureAtlasLayout>>());
+        let sheets = self
+            .sheets
+            .iter()
+            .map(|(name, sheet)| {
+                let layout = TextureAtlasLayout::from_grid(
+                    sheet.tile_size,
+                    sheet.columns,
+                    sheet.rows,
+                    None,
+                    None,
+                );
+                (
+                    name.clone(),
+                    SpriteSheet {
+                        image: asset_server.load(&sheet.image),
+                        layout: layouts.add(layout),
+                        clips: sheet.clips.clone(),
+                    },
+                )
+            })
+            .collect();
+        world.insert_resource(SpriteSheets(sheets));
+    }
+
+    fn count_progress(&self, asset_server: &AssetServer) -> Progress {
+        let mut done = 0;
+        let mut total = 0;
+        for sheet in self.sheets.values() {
+            total += 1;
+            done += asset_server
+                .get_path_id(&sheet.image)
+                .is_some_and(|id| asset_server.is_loaded_with_dependencies(id))
+                as u32;
+        }
+
+        Progress { done, total }
+    }
+}
+
+/// A sprite sheet laid out as a grid of frames.
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SpriteSheetConfig {
+    pub image: String,
+    pub tile_size: UVec2,
+    pub columns: u32,
+    pub rows: u32,
+    pub clips: HashMap<String, SpriteClip>,
+}
+
+/// A sequence of frames in a sprite sheet.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct SpriteClip {
+    /// Indices into the sprite sheet's grid, in row-major order.
+    pub frames: Vec<usize>,
+    /// The duration of each frame, in seconds.
+    pub frame_secs: f32,
+    pub looping: bool,
+    /// Named [`SpriteFrameEvent`]s to send on entering a frame, by position in `frames`.
+    #[serde(default)]
+    pub events: HashMap<usize, String>,
+}
+
+/// The loaded sprite sheets from [`SpriteAnimationConfig`], by name.
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+pub struct SpriteSheets(pub HashMap<String, SpriteSheet>);
+
+impl Configure for SpriteSheets {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+        app.add_systems(
+            Update,
+            state!(Screen::Title | Screen::Loading)
+                .on_update(SpriteAnimationConfig::progress.track_progress::<BevyState<Screen>>()),
+        );
+    }
+}
+
+#[derive(Reflect)]
+pub struct SpriteSheet {
+    pub image: Handle<Image>,
+    pub layout: Handle<TextureAtlasLayout>,
+    pub clips: HashMap<String, SpriteClip>,
+}
+
+/// Plays a [`SpriteClip`] on this entity's [`Sprite`].
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+#[require(Sprite)]
+pub struct SpriteAnimation {
+    pub sheet: String,
+    pub clip: String,
+    /// The current position in the clip's frames.
+    pub frame: usize,
+    /// Whether a non-looping clip has finished.
+    pub finished: bool,
+    elapsed: f32,
+    /// Whether the current frame has been displayed yet.
+    started: bool,
+}
+
+impl Configure for SpriteAnimation {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            Update,
+            update_sprite_animation
+                .in_set(UpdateSystems::SyncLate)
+                .in_set(PausableSystems),
+        );
+    }
+}
+
+impl SpriteAnimation {
+    pub fn new(sheet: impl Into<String>, clip: impl Into<String>) -> Self {
+        Self {
+            sheet: sheet.into(),
+            clip: clip.into(),
+            frame: 0,
+            finished: false,
+            elapsed: 0.0,
+            started: false,
+        }
+    }
+
+    /// Switch to a different clip, restarting it unless it's already playing.
+    pub fn play(&mut self, clip: &str) {
+        if self.clip == clip {
+            return;
+        }
+        self.clip = clip.to_string();
+        self.restart();
+    }
+
+    pub fn restart(&mut self) {
+        self.frame = 0;
+        self.finished = false;
+        self.elapsed = 0.0;
+        self.started = false;
+    }
+
+    /// Advance through the clip, calling `enter` with the position of each frame entered.
+    ///
+    /// The first call after a restart enters the first frame without advancing.
+    fn advance(&mut self, clip: &SpriteClip, dt: f32, mut enter: impl FnMut(usize)) {
+        if !self.started {
+            self.started = true;
+            enter(self.frame);
+            return;
+        }
+        rq!(!self.finished);
+
+        let frame_secs = clip.frame_secs.max(f32::EPSILON);
+        self.elapsed += dt;
+        while self.elapsed >= frame_secs {
+            self.elapsed -= frame_secs;
+            if self.frame + 1 < clip.frames.len() {
+                self.frame += 1;
+            } else if clip.looping {
+                self.frame = 0;
+            } else {
+                self.finished = true;
+                return;
+            }
+            enter(self.frame);
+        }
+    }
+}
+
+/// Sent when a [`SpriteAnimation`] enters a frame with an event in its [`SpriteClip`].
+#[derive(Event, Reflect, Clone, Debug)]
+pub struct SpriteFrameEvent {
+    pub entity: Entity,
+    pub name: String,
+}
+
+impl Configure for SpriteFrameEvent {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_event::<Self>();
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+pub fn update_sprite_animation(
+    time: Res<Time>,
+    sheets: Res<SpriteSheets>,
+    mut frame_events: EventWriter<SpriteFrameEvent>,
+    mut animation_query: Query<(Entity, &mut SpriteAnimation, &mut Sprite)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut animation, mut sprite) in &mut animation_query {
+        let sheet = cq!(sheets.0.get(&animation.sheet));
+        let clip = cq!(sheet.clips.get(&animation.clip));
+        cq!(!clip.frames.is_empty());
+
+        let mut entered_frame = false;
+        animation.advance(clip, dt, |frame| {
+            entered_frame = true;
+            if let Some(name) = clip.events.get(&frame) {
+                frame_events.write(SpriteFrameEvent {
+                    entity,
+                    name: name.clone(),
+                });
+            }
+        });
+        cq!(entered_frame);
+
+        // Attach the sprite sheet if it's not already attached.
+        if sprite.image != sheet.image {
+            sprite.image = sheet.image.clone();
+        }
+        let index = clip.frames[animation.frame.min(clip.frames.len() - 1)];
+        match &mut sprite.texture_atlas {
+            Some(atlas) if atlas.layout == sheet.layout => atlas.index = index,
+            atlas => {
+                *atlas = Some(TextureAtlas {
+                    layout: sheet.layout.clone(),
+                    index,
+                })
+            },
+        }
+    }
+}
+
+/// A state that determines which clip an entity's [`SpriteAnimation`] plays.
+///
+/// Gameplay systems set the state, and [`sync_animation_state`] switches clips to match.
+pub trait AnimationState: Component<Mutability = Mutable> + Copy + Eq {
+    fn clip(self) -> &'static str;
+
+    /// The state to switch to when this state's clip finishes, if any.
+    fn next(self) -> Option<Self> {
+        None
+    }
+}
+
+/// Switch clips when an [`AnimationState`] changes, and advance states when their clips finish.
+#[cfg_attr(feature = "native_dev", hot)]
+pub fn sync_animation_state<S: AnimationState>(
+    mut state_query: Query<(&mut S, &mut SpriteAnimation)>,
+) {
+    for (mut state, mut animation) in &mut state_query {
+        if animation.finished {
+            if let Some(next) = state.next() {
+                *state = next;
+            }
+        }
+        if state.is_changed() {
+            if animation.clip == state.clip() {
+                animation.restart();
+            } else {
+                animation.play(state.clip());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::event::EventCursor;
+
+    use super::*;
+
+    fn clip(looping: bool) -> SpriteClip {
+        SpriteClip {
+            frames: vec![4, 5, 6],
+            frame_secs: 0.25,
+            looping,
+            events: [(1, "step".to_string())].into_iter().collect(),
+        }
+    }
+
+    /// Advance the animation, returning the positions of the frames entered.
+    fn advance(animation: &mut SpriteAnimation, clip: &SpriteClip, dt: f32) -> Vec<usize> {
+        let mut entered = vec![];
+        animation.advance(clip, dt, |frame| entered.push(frame));
+        entered
+    }
+
+    #[test]
+    fn test_advance() {
+        let clip = clip(false);
+        let mut animation = SpriteAnimation::new("sheet", "clip");
+
+        // The first frame is displayed for a full frame before advancing.
+        assert_eq!(advance(&mut animation, &clip, 1.0), [0]);
+        assert!(advance(&mut animation, &clip, 0.125).is_empty());
+        assert_eq!(advance(&mut animation, &clip, 0.125), [1]);
+        assert_eq!(advance(&mut animation, &clip, 0.25), [2]);
+        assert!(!animation.finished);
+
+        assert!(advance(&mut animation, &clip, 0.25).is_empty());
+        assert!(animation.finished);
+        assert_eq!(animation.frame, 2);
+        assert!(advance(&mut animation, &clip, 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_loop() {
+        let clip = clip(true);
+        let mut animation = SpriteAnimation::new("sheet", "clip");
+
+        assert_eq!(advance(&mut animation, &clip, 0.0), [0]);
+        assert_eq!(advance(&mut animation, &clip, 0.75), [1, 2, 0]);
+        assert_eq!(advance(&mut animation, &clip, 0.5), [1, 2]);
+        assert!(!animation.finished);
+    }
+
+    #[test]
+    fn test_frame_events() {
+        let mut app = App::new();
+        app.add_event::<SpriteFrameEvent>();
+        app.insert_resource(Time::<()>::default());
+        app.insert_resource(SpriteSheets(
+            [(
+                "sheet".to_string(),
+                SpriteSheet {
+                    image: default(),
+                    layout: default(),
+                    clips: [("clip".to_string(), clip(true))].into_iter().collect(),
+                },
+            )]
+            .into_iter()
+            .collect(),
+        ));
+        app.add_systems(Update, update_sprite_animation);
+        let entity = app
+            .world_mut()
+            .spawn(SpriteAnimation::new("sheet", "clip"))
+            .id();
+        let mut cursor = EventCursor::<SpriteFrameEvent>::default();
+        let mut step = |secs: f32| {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(secs));
+            app.update();
+            cursor
+                .read(app.world().resource::<Events<SpriteFrameEvent>>())
+                .count()
+        };
+
+        assert_eq!(step(0.0), 0);
+        assert_eq!(step(0.25), 1);
+        // Leaving the event frame doesn't send it again.
+        assert_eq!(step(0.125), 0);
+        assert_eq!(step(0.125), 0);
+        // Crossing the event frame twice in one update sends it twice.
+        assert_eq!(step(1.5), 2);
+
+        let sprite = app.world().get::<Sprite>(entity).unwrap();
+        assert_eq!(sprite.texture_atlas.as_ref().unwrap().index, 6);
+    }
+}
@@ -0,0 +1,452 @@
+//! Time-based animation of other animation components, with easing.
+
+use bevy::ecs::component::Mutable;
+use bevy::ecs::query::QueryFilter;
+use bevy::ecs::schedule::ScheduleConfigs;
+use bevy::ecs::system::ScheduleSystem;
+use bevy::reflect::GetTypeRegistration;
+use bevy::reflect::Typed;
+
+use crate::animation::PostColorSystems;
+use crate::animation::PostTransformSystems;
+use crate::animation::backup::Backup;
+use crate::animation::offset::NodeOffset;
+use crate::animation::offset::Offset;
+use crate::animation::offset::apply_node_offset;
+use crate::animation::offset::apply_offset;
+use crate::prelude::*;
+use crate::theme::color::ColorMut;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_event::<TweenFinished>();
+    app.configure::<(
+        Tween<OffsetLens>,
+        Tween<NodeOffsetLens>,
+        Tween<ScaleLens>,
+        Tween<RotationLens>,
+        Tween<AlphaLens<Sprite>>,
+        Tween<AlphaLens<ImageNode>>,
+        Tween<AlphaLens<BackgroundColor>>,
+        Tween<AlphaLens<BorderColor>>,
+    )>();
+
+    app.register_required_components::<Tween<OffsetLens>, Offset>();
+    app.register_required_components::<Tween<NodeOffsetLens>, NodeOffset>();
+    app.register_required_components::<Tween<ScaleLens>, Backup<Transform>>();
+    app.register_required_components::<Tween<RotationLens>, Backup<Transform>>();
+}
+
+/// A value that a [`Tween`] animates on its target component.
+pub trait Lens:
+    Reflect + FromReflect + TypePath + Typed + GetTypeRegistration + Send + Sync + 'static
+{
+    type Target: Component<Mutability = Mutable>;
+    type Filter: QueryFilter;
+
+    /// Apply the value at eased progress `t`.
+    fn apply(&self, target: &mut Self::Target, t: f32);
+
+    /// Schedule the system that applies this lens in [`PostUpdate`].
+    fn schedule(system: ScheduleConfigs<ScheduleSystem>) -> ScheduleConfigs<ScheduleSystem> {
+        system.in_set(PostTransformSystems::Blend)
+    }
+}
+
+/// How a [`Tween`] repeats.
+#[derive(Reflect, Copy, Clone, Default, Eq, PartialEq, Debug)]
+pub enum TweenMode {
+    /// Play once and hold the final value.
+    #[default]
+    Once,
+    /// Restart from the beginning after each cycle.
+    Loop,
+    /// Alternate between playing forwards and backwards.
+    PingPong,
+}
+
+/// Animates a [`Lens`] over time.
+///
+/// Sends [`TweenFinished`] at the end of each cycle. Advances in real time, so UI tweens
+/// are unaffected by [`TimeDilation`](crate::core::time::TimeDilation).
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct Tween<L: Lens> {
+    pub lens: L,
+    /// The duration of one cycle, in seconds.
+    pub duration: f32,
+    pub ease: EaseFunction,
+    pub mode: TweenMode,
+    elapsed: f32,
+}
+
+impl<L: Lens> Configure for Tween<L> {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(PostUpdate, L::schedule(apply_tween::<L>.into_configs()));
+    }
+}
+
+impl<L: Lens> Tween<L> {
+    pub fn new(lens: L, duration: f32) -> Self {
+        Self {
+            lens,
+            duration,
+            ease: EaseFunction::Linear,
+            mode: TweenMode::Once,
+            elapsed: 0.0,
+        }
+    }
+
+    pub fn with_ease(mut self, ease: EaseFunction) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    pub fn with_mode(mut self, mode: TweenMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Advance the tween, returning whether a cycle finished.
+    fn tick(&mut self, dt: f32) -> bool {
+        let duration = self.duration.max(f32::EPSILON);
+        let before = self.elapsed / duration;
+        self.elapsed += dt;
+        let after = self.elapsed / duration;
+        match self.mode {
+            TweenMode::Once => before < 1.0 && after >= 1.0,
+            TweenMode::Loop | TweenMode::PingPong => before.floor() != after.floor(),
+        }
+    }
+
+    /// The progress through the current cycle, from `0.0` to `1.0`, before easing.
+    fn progress(&self) -> f32 {
+        let t = self.elapsed / self.duration.max(f32::EPSILON);
+        match self.mode {
+            TweenMode::Once => t.min(1.0),
+            TweenMode::Loop => t.fract(),
+            TweenMode::PingPong => {
+                let t = t % 2.0;
+                if t > 1.0 { 2.0 - t } else { t }
+            },
+        }
+    }
+}
+
+/// Sent when a [`Tween`] finishes a cycle.
+#[derive(Event, Copy, Clone, Debug)]
+pub struct TweenFinished {
+    pub entity: Entity,
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn apply_tween<L: Lens>(
+    time: Res<Time<Real>>,
+    mut finished_events: EventWriter<TweenFinished>,
+    mut tween_query: Query<(Entity, &mut Tween<L>, &mut L::Target), L::Filter>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut tween, mut target) in &mut tween_query {
+        if tween.tick(dt) {
+            finished_events.write(TweenFinished { entity });
+        }
+        let t = tween.ease.sample_clamped(tween.progress());
+        tween.lens.apply(&mut target, t);
+    }
+}
+
+/// Animates [`Offset`].
+#[derive(Reflect, Copy, Clone, Debug)]
+pub struct OffsetLens {
+    pub from: Vec2,
+    pub to: Vec2,
+}
+
+impl Lens for OffsetLens {
+    type Target = Offset;
+    type Filter = ();
+
+    fn apply(&self, target: &mut Self::Target, t: f32) {
+        target.0 = self.from.lerp(self.to, t);
+    }
+
+    fn schedule(system: ScheduleConfigs<ScheduleSystem>) -> ScheduleConfigs<ScheduleSystem> {
+        system
+            .in_set(PostTransformSystems::Blend)
+            .before(apply_offset)
+    }
+}
+
+/// Animates [`NodeOffset`].
+///
+/// Values with different units jump halfway through instead of interpolating.
+#[derive(Reflect, Copy, Clone)]
+pub struct NodeOffsetLens {
+    pub from: NodeOffset,
+    pub to: NodeOffset,
+}
+
+impl Lens for NodeOffsetLens {
+    type Target = NodeOffset;
+    type Filter = ();
+
+    fn apply(&self, target: &mut Self::Target, t: f32) {
+        target.x = lerp_val(self.from.x, self.to.x, t);
+        target.y = lerp_val(self.from.y, self.to.y, t);
+    }
+
+    fn schedule(system: ScheduleConfigs<ScheduleSystem>) -> ScheduleConfigs<ScheduleSystem> {
+        system
+            .in_set(PostTransformSystems::Blend)
+            .before(apply_node_offset)
+    }
+}
+
+fn lerp_val(from: Val, to: Val, t: f32) -> Val {
+    match (from, to) {
+        (Px(a), Px(b)) => Px(a.lerp(b, t)),
+        (Percent(a), Percent(b)) => Percent(a.lerp(b, t)),
+        (Vw(a), Vw(b)) => Vw(a.lerp(b, t)),
+        (Vh(a), Vh(b)) => Vh(a.lerp(b, t)),
+        (VMin(a), VMin(b)) => VMin(a.lerp(b, t)),
+        (VMax(a), VMax(b)) => VMax(a.lerp(b, t)),
+        _ if t < 0.5 => from,
+        _ => to,
+    }
+}
+
+/// Animates [`Transform`] scale, multiplied on top of the current scale.
+#[derive(Reflect, Copy, Clone, Debug)]
+pub struct ScaleLens {
+    pub from: Vec2,
+    pub to: Vec2,
+}
+
+impl Lens for ScaleLens {
+    type Target = Transform;
+    type Filter = ();
+
+    fn apply(&self, target: &mut Self::Target, t: f32) {
+        target.scale *= self.from.lerp(self.to, t).extend(1.0);
+    }
+}
+
+/// Animates [`Transform`] rotation in radians, added on top of the current rotation.
+#[derive(Reflect, Copy, Clone, Debug)]
+pub struct RotationLens {
+    pub from: f32,
+    pub to: f32,
+}
+
+impl Lens for RotationLens {
+    type Target = Transform;
+    type Filter = ();
+
+    fn apply(&self, target: &mut Self::Target, t: f32) {
+        target.rotate_z(self.from.lerp(self.to, t));
+    }
+}
+
+/// Animates the alpha of a [`ThemeColorFor`] color, multiplied on top of the theme color.
+#[derive(Reflect)]
+pub struct AlphaLens<C: ColorMut> {
+    pub from: f32,
+    pub to: f32,
+    #[reflect(ignore)]
+    _phantom: PhantomData<C>,
+}
+
+impl<C: ColorMut + TypePath> Lens for AlphaLens<C> {
+    type Target = C;
+    type Filter = With<ThemeColorFor<C>>;
+
+    fn apply(&self, target: &mut Self::Target, t: f32) {
+        let color = target.color_mut();
+        color.set_alpha(color.alpha() * self.from.lerp(self.to, t));
+    }
+
+    fn schedule(system: ScheduleConfigs<ScheduleSystem>) -> ScheduleConfigs<ScheduleSystem> {
+        system.in_set(PostColorSystems::Blend)
+    }
+}
+
+impl<C: ColorMut> AlphaLens<C> {
+    pub fn new(from: f32, to: f32) -> Self {
+        Self {
+            from,
+            to,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::event::EventCursor;
+
+    use super::*;
+
+    fn tween(mode: TweenMode) -> Tween<ScaleLens> {
+        Tween::new(
+            ScaleLens {
+                from: Vec2::ONE,
+                to: Vec2::splat(2.0),
+            },
+            1.0,
+        )
+        .with_mode(mode)
+    }
+
+    /// Tick the tween by each delta, returning the progress and whether a cycle finished.
+    fn tick_all(mode: TweenMode, deltas: &[f32]) -> Vec<(f32, bool)> {
+        let mut tween = tween(mode);
+        deltas
+            .iter()
+            .map(|&dt| {
+                let finished = tween.tick(dt);
+                (tween.progress(), finished)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_once() {
+        assert_eq!(tween(TweenMode::Once).progress(), 0.0);
+        assert_eq!(
+            tick_all(TweenMode::Once, &[0.5, 0.5, 0.5]),
+            [(0.5, false), (1.0, true), (1.0, false)],
+        );
+    }
+
+    #[test]
+    fn test_loop() {
+        assert_eq!(
+            tick_all(TweenMode::Loop, &[0.75, 0.25, 0.25, 1.0]),
+            [(0.75, false), (0.0, true), (0.25, false), (0.25, true)],
+        );
+    }
+
+    #[test]
+    fn test_ping_pong() {
+        assert_eq!(
+            tick_all(TweenMode::PingPong, &[0.75, 0.25, 0.25, 0.75, 0.25]),
+            [
+                (0.75, false),
+                (1.0, true),
+                (0.75, false),
+                (0.0, true),
+                (0.25, false),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_zero_duration() {
+        let mut tween = tween(TweenMode::Once);
+        tween.duration = 0.0;
+        assert_eq!(tween.progress(), 0.0);
+        assert!(tween.tick(f32::EPSILON));
+        assert_eq!(tween.progress(), 1.0);
+    }
+
+    #[test]
+    fn test_tween_finished() {
+        let mut app = App::new();
+        app.add_event::<TweenFinished>();
+        app.insert_resource(Time::<Real>::default());
+        app.add_systems(Update, apply_tween::<ScaleLens>);
+        let once = app
+            .world_mut()
+            .spawn((tween(TweenMode::Once), Transform::default()))
+            .id();
+        let looping = app
+            .world_mut()
+            .spawn((tween(TweenMode::Loop), Transform::default()))
+            .id();
+        let ping_pong = app
+            .world_mut()
+            .spawn((tween(TweenMode::PingPong), Transform::default()))
+            .id();
+        let mut cursor = EventCursor::<TweenFinished>::default();
+        let mut step = |secs: f32| {
+            app.world_mut()
+                .resource_mut::<Time<Real>>()
+                .advance_by(Duration::from_secs_f32(secs));
+            app.update();
+            let mut finished = cursor
+                .read(app.world().resource::<Events<TweenFinished>>())
+                .map(|x| x.entity)
+                .collect::<Vec<_>>();
+            finished.sort();
+            finished
+        };
+        let mut all = vec![once, looping, ping_pong];
+        all.sort();
+
+        assert!(step(0.5).is_empty());
+        assert_eq!(step(0.5), all);
+        assert_eq!(step(1.0), [looping, ping_pong]);
+        assert!(step(0.5).is_empty());
+        assert_eq!(step(0.5), [looping, ping_pong]);
+    }
+
+    #[test]
+    fn test_offset_lens() {
+        let lens = OffsetLens {
+            from: Vec2::ZERO,
+            to: vec2(4.0, -8.0),
+        };
+        let mut offset = Offset::default();
+        lens.apply(&mut offset, 0.25);
+        assert_eq!(offset.0, vec2(1.0, -2.0));
+    }
+
+    #[test]
+    fn test_node_offset_lens() {
+        let lens = NodeOffsetLens {
+            from: NodeOffset::new(Px(0.0), Percent(10.0)),
+            to: NodeOffset::new(Px(8.0), Vw(20.0)),
+        };
+        let mut offset = NodeOffset::default();
+
+        lens.apply(&mut offset, 0.25);
+        assert_eq!(offset.x, Px(2.0));
+        // Different units jump halfway through.
+        assert_eq!(offset.y, Percent(10.0));
+
+        lens.apply(&mut offset, 0.5);
+        assert_eq!(offset.x, Px(4.0));
+        assert_eq!(offset.y, Vw(20.0));
+    }
+
+    #[test]
+    fn test_scale_lens() {
+        let lens = ScaleLens {
+            from: Vec2::ONE,
+            to: Vec2::splat(3.0),
+        };
+        let mut transform = Transform::from_scale(Vec3::splat(2.0));
+        lens.apply(&mut transform, 0.5);
+        assert_eq!(transform.scale, vec3(4.0, 4.0, 2.0));
+    }
+
+    #[test]
+    fn test_rotation_lens() {
+        let lens = RotationLens { from: 0.0, to: 1.0 };
+        let mut transform = Transform::from_rotation(Quat::from_rotation_z(0.5));
+        lens.apply(&mut transform, 0.5);
+        assert!(
+            transform
+                .rotation
+                .abs_diff_eq(Quat::from_rotation_z(1.0), 1e-6)
+        );
+    }
+
+    #[test]
+    fn test_alpha_lens() {
+        let lens = AlphaLens::<Sprite>::new(1.0, 0.0);
+        let mut sprite = Sprite::from_color(Color::WHITE.with_alpha(0.5), Vec2::ONE);
+        lens.apply(&mut sprite, 0.5);
+        assert_eq!(sprite.color.alpha(), 0.25);
+    }
+}
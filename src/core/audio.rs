@@ -1,9 +1,11 @@
 use bevy::audio::AudioPlugin;
+use bevy::audio::SpatialScale;
 
+use crate::core::camera::CameraRoot;
 use crate::prelude::*;
 
 pub(super) fn plugin(app: &mut App) {
-    app.configure::<AudioSettings>();
+    app.configure::<(AudioSettings, AudioBus, AudioGain, SpatialFalloff)>();
 
     app.add_plugins(AudioPlugin::default());
 }
@@ -14,14 +16,28 @@ pub struct AudioSettings {
     pub master_volume: f32,
     pub music_volume: f32,
     pub ui_volume: f32,
+    // Newer buses default when loading settings saved before they existed.
+    #[reflect(default = "default_volume")]
+    pub sfx_volume: f32,
+    #[reflect(default = "default_volume")]
+    pub ambience_volume: f32,
+    #[reflect(default = "default_volume")]
+    pub voice_volume: f32,
+}
+
+fn default_volume() -> f32 {
+    0.5
 }
 
 impl Default for AudioSettings {
     fn default() -> Self {
         Self {
-            master_volume: 0.5,
-            music_volume: 0.5,
-            ui_volume: 0.5,
+            master_volume: default_volume(),
+            music_volume: default_volume(),
+            ui_volume: default_volume(),
+            sfx_volume: default_volume(),
+            ambience_volume: default_volume(),
+            voice_volume: default_volume(),
         }
     }
 }
@@ -30,94 +46,301 @@ impl Configure for AudioSettings {
     fn configure(app: &mut App) {
         app.register_type::<Self>();
         app.init_resource::<Self>();
-        app.configure::<(IsMusicAudio, IsUiAudio)>();
         app.add_systems(
             Update,
-            apply_audio_settings
-                .run_if(resource_changed::<Self>)
-                .in_set(UpdateSystems::Update),
+            (
+                apply_audio_settings.run_if(resource_changed::<Self>),
+                apply_audio_gain,
+            )
+                .chain()
+                .in_set(UpdateSystems::SyncLate)
+                .after(apply_spatial_falloff),
         );
     }
 }
 
 impl AudioSettings {
-    pub fn music_volume(&self) -> Volume {
-        Volume::Linear(self.master_volume * self.music_volume)
+    pub fn volume(&self, bus: AudioBus) -> Volume {
+        let bus_volume = match bus {
+            AudioBus::Music => self.music_volume,
+            AudioBus::Ui => self.ui_volume,
+            AudioBus::Sfx => self.sfx_volume,
+            AudioBus::Ambience => self.ambience_volume,
+            AudioBus::Voice => self.voice_volume,
+        };
+        Volume::Linear(self.master_volume * bus_volume)
     }
+}
 
-    pub fn ui_volume(&self) -> Volume {
-        Volume::Linear(self.master_volume * self.ui_volume)
+#[cfg_attr(feature = "native_dev", hot)]
+fn apply_audio_settings(
+    audio_settings: Res<AudioSettings>,
+    mut audio_query: Query<(
+        &AudioBus,
+        Option<&AudioGain>,
+        Option<&mut PlaybackSettings>,
+        Option<&mut AudioSink>,
+        Option<&mut SpatialAudioSink>,
+    )>,
+) {
+    for (&bus, gain, playback, sink, spatial_sink) in &mut audio_query {
+        let gain = gain.map_or(1.0, |x| x.0);
+        let volume = audio_settings.volume(bus) * Volume::Linear(gain);
+        set_volume(volume, playback, sink, spatial_sink);
     }
 }
 
 #[cfg_attr(feature = "native_dev", hot)]
-fn apply_audio_settings(
+fn apply_audio_gain(
     audio_settings: Res<AudioSettings>,
-    music_audio_query: Query<Entity, With<IsMusicAudio>>,
-    ui_audio_query: Query<Entity, With<IsUiAudio>>,
-    mut volume_query: Query<(Option<&mut PlaybackSettings>, Option<&mut AudioSink>)>,
+    mut audio_query: Query<
+        (
+            &AudioBus,
+            &AudioGain,
+            Option<&mut PlaybackSettings>,
+            Option<&mut AudioSink>,
+            Option<&mut SpatialAudioSink>,
+        ),
+        Changed<AudioGain>,
+    >,
 ) {
-    // Apply music volume.
-    let volume = audio_settings.music_volume();
-    for entity in &music_audio_query {
-        let (playback, sink) = c!(volume_query.get_mut(entity));
-
-        if let Some(mut sink) = sink {
-            sink.set_volume(volume);
-        } else if let Some(mut playback) = playback {
-            playback.volume = volume;
-        }
+    for (&bus, gain, playback, sink, spatial_sink) in &mut audio_query {
+        let volume = audio_settings.volume(bus) * Volume::Linear(gain.0);
+        set_volume(volume, playback, sink, spatial_sink);
+    }
+}
+
+fn set_volume(
+    volume: Volume,
+    playback: Option<Mut<PlaybackSettings>>,
+    sink: Option<Mut<AudioSink>>,
+    spatial_sink: Option<Mut<SpatialAudioSink>>,
+) {
+    if let Some(mut sink) = sink {
+        sink.set_volume(volume);
+    } else if let Some(mut sink) = spatial_sink {
+        sink.set_volume(volume);
+    } else if let Some(mut playback) = playback {
+        playback.volume = volume;
     }
+}
 
-    // Apply UI volume.
-    let volume = audio_settings.ui_volume();
-    for entity in &ui_audio_query {
-        let (playback, sink) = c!(volume_query.get_mut(entity));
+/// A volume category for audio, with its own volume in [`AudioSettings`].
+///
+/// The volume is set from the current [`AudioSettings`] whenever [`PlaybackSettings`] is inserted.
+#[derive(Component, Reflect, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[reflect(Component)]
+pub enum AudioBus {
+    Music,
+    Ui,
+    Sfx,
+    Ambience,
+    Voice,
+}
 
-        if let Some(mut sink) = sink {
-            sink.set_volume(volume);
-        } else if let Some(mut playback) = playback {
-            playback.volume = volume;
+impl Configure for AudioBus {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_observer(apply_initial_volume);
+    }
+}
+
+impl AudioBus {
+    pub const ALL: [Self; 5] = [
+        Self::Music,
+        Self::Ui,
+        Self::Sfx,
+        Self::Ambience,
+        Self::Voice,
+    ];
+
+    /// The reflect path to this bus's volume in [`AudioSettings`].
+    pub fn volume_path(self) -> &'static str {
+        match self {
+            Self::Music => "music_volume",
+            Self::Ui => "ui_volume",
+            Self::Sfx => "sfx_volume",
+            Self::Ambience => "ambience_volume",
+            Self::Voice => "voice_volume",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Music => "Music",
+            Self::Ui => "UI",
+            Self::Sfx => "Effects",
+            Self::Ambience => "Ambience",
+            Self::Voice => "Voice",
         }
     }
 }
 
-#[derive(Component, Reflect, Debug)]
+fn apply_initial_volume(
+    trigger: Trigger<OnInsert, PlaybackSettings>,
+    audio_settings: Res<AudioSettings>,
+    mut audio_query: Query<(&AudioBus, Option<&AudioGain>, &mut PlaybackSettings)>,
+) {
+    let target = r!(trigger.get_target());
+    let (&bus, gain, mut playback) = rq!(audio_query.get_mut(target));
+    let gain = gain.map_or(1.0, |x| x.0);
+    playback.volume = audio_settings.volume(bus) * Volume::Linear(gain);
+}
+
+/// A volume multiplier for a single audio entity, on top of its [`AudioBus`] volume.
+///
+/// Useful for fading audio in and out.
+#[derive(Component, Reflect, Copy, Clone, PartialEq, Debug)]
 #[reflect(Component)]
-struct IsMusicAudio;
+pub struct AudioGain(pub f32);
 
-impl Configure for IsMusicAudio {
+impl Configure for AudioGain {
     fn configure(app: &mut App) {
         app.register_type::<Self>();
     }
 }
 
-pub fn music_audio(audio_settings: &AudioSettings, handle: Handle<AudioSource>) -> impl Bundle {
+impl Default for AudioGain {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+pub fn music_audio(handle: Handle<AudioSource>) -> impl Bundle {
     (
         Name::new("MusicAudio"),
         AudioPlayer(handle),
-        PlaybackSettings::LOOP.with_volume(audio_settings.music_volume()),
-        IsMusicAudio,
+        PlaybackSettings::LOOP,
+        AudioBus::Music,
+    )
+}
+
+pub fn ui_audio(handle: Handle<AudioSource>, speed: f32, gain: f32) -> impl Bundle {
+    (
+        Name::new("UiSample"),
+        AudioPlayer(handle),
+        PlaybackSettings::DESPAWN.with_speed(speed),
+        AudioBus::Ui,
+        AudioGain(gain),
     )
 }
 
-#[derive(Component, Reflect, Debug)]
+/// A one-shot sample on the given bus.
+pub fn sfx_audio(bus: AudioBus, handle: Handle<AudioSource>) -> impl Bundle {
+    (
+        Name::new("SfxSample"),
+        AudioPlayer(handle),
+        PlaybackSettings::DESPAWN,
+        bus,
+    )
+}
+
+/// A one-shot sample on the SFX bus that pans and attenuates relative to the primary camera.
+pub fn spatial_sfx(
+    handle: Handle<AudioSource>,
+    position: Vec2,
+    falloff: SpatialFalloff,
+) -> impl Bundle {
+    (
+        sfx_audio(AudioBus::Sfx, handle),
+        Transform::from_translation(position.extend(0.0)),
+        falloff,
+    )
+}
+
+/// Linear volume falloff with distance from the primary camera, in world units.
+///
+/// Makes the entity's [`PlaybackSettings`] spatial when inserted.
+#[derive(Component, Reflect, Copy, Clone, Debug)]
 #[reflect(Component)]
-struct IsUiAudio;
+#[require(AudioGain)]
+pub struct SpatialFalloff {
+    /// The distance within which the volume is at its loudest.
+    pub near: f32,
+    /// The distance beyond which the audio is silent.
+    pub far: f32,
+}
 
-impl Configure for IsUiAudio {
+impl Configure for SpatialFalloff {
     fn configure(app: &mut App) {
         app.register_type::<Self>();
+        app.add_observer(enable_spatial_playback);
+        app.add_systems(
+            Update,
+            apply_spatial_falloff.in_set(UpdateSystems::SyncLate),
+        );
     }
 }
 
-pub fn ui_audio(audio_settings: &AudioSettings, handle: Handle<AudioSource>) -> impl Bundle {
-    (
-        Name::new("UiSample"),
-        AudioPlayer(handle),
-        PlaybackSettings::DESPAWN
-            .with_volume(audio_settings.ui_volume())
-            .with_speed(thread_rng().gen_range(0.9..1.5)),
-        IsUiAudio,
-    )
+impl Default for SpatialFalloff {
+    fn default() -> Self {
+        Self {
+            near: 200.0,
+            far: 1200.0,
+        }
+    }
+}
+
+impl SpatialFalloff {
+    /// The volume multiplier at a given distance from the listener.
+    pub fn gain(&self, distance: f32) -> f32 {
+        let range = (self.far - self.near).max(f32::EPSILON);
+        1.0 - ((distance - self.near) / range).clamp(0.0, 1.0)
+    }
+}
+
+fn enable_spatial_playback(
+    trigger: Trigger<OnInsert, SpatialFalloff>,
+    mut falloff_query: Query<(&SpatialFalloff, &mut PlaybackSettings)>,
+) {
+    let target = r!(trigger.get_target());
+    let (falloff, mut playback) = rq!(falloff_query.get_mut(target));
+    playback.spatial = true;
+    // Scale positions so that rodio's own distance attenuation never kicks in
+    // within the falloff range, leaving attenuation to `SpatialFalloff`.
+    playback.spatial_scale = Some(SpatialScale::new_2d(1.0 / falloff.far.max(1.0)));
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn apply_spatial_falloff(
+    camera_root: Res<CameraRoot>,
+    camera_query: Query<&GlobalTransform>,
+    mut falloff_query: Query<(&SpatialFalloff, &Transform, &mut AudioGain)>,
+) {
+    let listener = r!(camera_query.get(camera_root.primary)).translation().xy();
+    for (falloff, transform, mut gain) in &mut falloff_query {
+        let distance = transform.translation.xy().distance(listener);
+        gain.set_if_neq(AudioGain(falloff.gain(distance)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::reflect::TypeRegistry;
+    use bevy::reflect::serde::TypedReflectDeserializer;
+    use serde::de::DeserializeSeed as _;
+
+    use super::*;
+
+    #[test]
+    fn test_load_settings_without_new_buses() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<AudioSettings>();
+        let registration = registry
+            .get(core::any::TypeId::of::<AudioSettings>())
+            .unwrap();
+        let mut deserializer =
+            ron::Deserializer::from_str("(master_volume: 0.4, music_volume: 0.8, ui_volume: 0.2)")
+                .unwrap();
+
+        let value = TypedReflectDeserializer::new(registration, &registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let settings = AudioSettings::from_reflect(&*value).unwrap();
+
+        assert_eq!(settings.master_volume, 0.4);
+        assert_eq!(settings.music_volume, 0.8);
+        assert_eq!(settings.ui_volume, 0.2);
+        assert_eq!(settings.sfx_volume, default_volume());
+    }
 }
@@ -1,24 +1,57 @@
+use bevy::render::camera::RenderTarget;
+use bevy::render::render_resource::Extent3d;
+use bevy::render::render_resource::TextureDescriptor;
+use bevy::render::render_resource::TextureDimension;
+use bevy::render::render_resource::TextureFormat;
+use bevy::render::render_resource::TextureUsages;
+use bevy::render::view::RenderLayers;
+
+use crate::animation::PostTransformSystems;
+use crate::animation::offset::Offset;
+use crate::core::window::DisplaySettings;
 use crate::prelude::*;
 
 pub(super) fn plugin(app: &mut App) {
-    app.configure::<(CameraRoot, SmoothFollow, AbsoluteScale)>();
+    app.configure::<(
+        CameraRoot,
+        SmoothFollow,
+        CameraTrauma,
+        CameraZoom,
+        AbsoluteScale,
+        PixelSnap,
+    )>();
 }
 
+/// The distance between the primary camera's ears for spatial audio, in world units.
+const SPATIAL_EAR_GAP: f32 = 400.0;
+
 #[derive(Resource, Reflect)]
 #[reflect(Resource)]
 pub struct CameraRoot {
     pub primary: Entity,
+    /// Draws the primary camera's output scaled up to the window in pixel-perfect mode.
+    pub canvas_camera: Entity,
+    /// The primary camera's render target in pixel-perfect mode.
+    pub canvas_image: Handle<Image>,
 }
 
 impl Configure for CameraRoot {
     fn configure(app: &mut App) {
         app.register_type::<Self>();
         app.init_resource::<Self>();
+        app.add_systems(Update, apply_pixel_perfect.in_set(UpdateSystems::SyncLate));
     }
 }
 
 impl FromWorld for CameraRoot {
     fn from_world(world: &mut World) -> Self {
+        let canvas_image = world.resource_mut::<Assets<Image>>().add(canvas_image());
+        world.spawn((
+            Name::new("Canvas"),
+            Sprite::from_image(canvas_image.clone()),
+            RenderLayers::layer(CANVAS_LAYER),
+        ));
+
         Self {
             primary: world
                 .spawn((
@@ -29,25 +62,127 @@ impl FromWorld for CameraRoot {
                         ..OrthographicProjection::default_2d()
                     }),
                     Msaa::Off,
-                    SmoothFollow {
-                        target: Entity::PLACEHOLDER,
-                        rate: Vec2::splat(100.0),
-                    },
+                    SpatialListener::new(SPATIAL_EAR_GAP),
+                    CameraTrauma::default(),
+                    SmoothFollow::default(),
+                    CameraZoom::default(),
                     IsDefaultUiCamera,
                 ))
                 .id(),
+            canvas_camera: world
+                .spawn((
+                    Name::new("CanvasCamera"),
+                    Camera2d,
+                    Camera {
+                        order: 1,
+                        is_active: false,
+                        ..default()
+                    },
+                    Msaa::Off,
+                    RenderLayers::layer(CANVAS_LAYER),
+                ))
+                .id(),
+            canvas_image,
         }
     }
 }
 
+/// The virtual resolution to render at in pixel-perfect mode (see [`DisplaySettings`]).
+const VIRTUAL_RESOLUTION: UVec2 = UVec2::new(640, 360);
+
+/// The render layer of the canvas that displays the primary camera in pixel-perfect mode.
+const CANVAS_LAYER: usize = 1;
+
+fn canvas_image() -> Image {
+    let size = Extent3d {
+        width: VIRTUAL_RESOLUTION.x,
+        height: VIRTUAL_RESOLUTION.y,
+        ..default()
+    };
+    let mut image = Image {
+        texture_descriptor: TextureDescriptor {
+            label: Some("canvas"),
+            size,
+            dimension: TextureDimension::D2,
+            format: TextureFormat::Bgra8UnormSrgb,
+            mip_level_count: 1,
+            sample_count: 1,
+            usage: TextureUsages::TEXTURE_BINDING
+                | TextureUsages::COPY_DST
+                | TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        },
+        ..default()
+    };
+    image.resize(size);
+    image
+}
+
+/// Render the primary camera to the canvas in pixel-perfect mode, and scale the canvas up
+/// to the window by the largest integer factor that fits.
+#[cfg_attr(feature = "native_dev", hot)]
+fn apply_pixel_perfect(
+    mut commands: Commands,
+    display_settings: Res<DisplaySettings>,
+    camera_root: Res<CameraRoot>,
+    mut camera_query: Query<(&mut Camera, &mut Projection)>,
+) {
+    let enabled = display_settings.pixel_perfect;
+    if display_settings.is_changed() {
+        let (mut primary, _) = r!(camera_query.get_mut(camera_root.primary));
+        primary.target = if enabled {
+            RenderTarget::Image(camera_root.canvas_image.clone().into())
+        } else {
+            RenderTarget::default()
+        };
+
+        // The UI is drawn at full resolution by whichever camera draws to the window.
+        let (ui_camera, other_camera) = if enabled {
+            (camera_root.canvas_camera, camera_root.primary)
+        } else {
+            (camera_root.primary, camera_root.canvas_camera)
+        };
+        commands.entity(ui_camera).insert(IsDefaultUiCamera);
+        commands.entity(other_camera).remove::<IsDefaultUiCamera>();
+    }
+
+    let (mut canvas_camera, mut projection) = r!(camera_query.get_mut(camera_root.canvas_camera));
+    if canvas_camera.is_active != enabled {
+        canvas_camera.is_active = enabled;
+    }
+    rq!(enabled);
+    let Projection::Orthographic(projection) = &mut *projection else {
+        return;
+    };
+    let viewport = rq!(canvas_camera.physical_viewport_size()).as_vec2();
+    let scale_factor = canvas_camera.target_scaling_factor().unwrap_or(1.0);
+    let factor = (viewport / VIRTUAL_RESOLUTION.as_vec2())
+        .min_element()
+        .floor()
+        .max(1.0);
+    let scale = scale_factor / factor;
+    if projection.scale != scale {
+        projection.scale = scale;
+    }
+}
+
 /// Follow a target entity smoothly.
 ///
 /// This component should only be used on root entities.
 #[derive(Component, Reflect)]
 #[reflect(Component)]
-struct SmoothFollow {
-    target: Entity,
-    rate: Vec2,
+pub struct SmoothFollow {
+    /// The entity to follow, if any.
+    pub target: Option<Entity>,
+    /// The exponential decay rate towards the target per second, by axis.
+    pub rate: Vec2,
+    /// The half-size of a rectangle around the follower that the target can move within
+    /// without being followed.
+    pub deadzone: Vec2,
+    /// How far ahead of the target to aim, in seconds of its [`LinearVelocity`].
+    pub look_ahead: f32,
+    /// A rectangle to keep the camera's view (or the follower's position) within.
+    pub bounds: Option<Rect>,
 }
 
 impl Configure for SmoothFollow {
@@ -57,18 +192,272 @@ impl Configure for SmoothFollow {
     }
 }
 
+impl Default for SmoothFollow {
+    fn default() -> Self {
+        Self {
+            target: None,
+            rate: Vec2::splat(6.0),
+            deadzone: Vec2::splat(24.0),
+            look_ahead: 0.25,
+            bounds: None,
+        }
+    }
+}
+
+impl SmoothFollow {
+    pub fn follow(&mut self, target: Entity) {
+        self.target = Some(target);
+    }
+
+    pub fn unfollow(&mut self) {
+        self.target = None;
+    }
+
+    /// Stop following and clear the bounds.
+    pub fn reset(&mut self) {
+        self.target = None;
+        self.bounds = None;
+    }
+}
+
 #[cfg_attr(feature = "native_dev", hot)]
 fn apply_smooth_follow(
     time: Res<Time>,
-    mut follow_query: Query<(&mut Transform, &SmoothFollow)>,
-    target_query: Query<&GlobalTransform, Without<SmoothFollow>>,
+    mut follow_query: Query<(&mut Transform, &SmoothFollow, Option<&Projection>)>,
+    target_query: Query<(&GlobalTransform, Option<&LinearVelocity>), Without<SmoothFollow>>,
 ) {
     let dt = time.delta_secs();
-    for (mut transform, follow) in &mut follow_query {
-        let target_pos = cq!(target_query.get(follow.target)).translation().xy();
+    for (mut transform, follow, projection) in &mut follow_query {
+        let target = cq!(follow.target);
+        let (target_gt, velocity) = cq!(target_query.get(target));
         let mut pos = transform.translation.xy();
-        pos += (target_pos - pos) * (follow.rate * dt).clamp(Vec2::ZERO, Vec2::ONE);
+
+        // Aim ahead of the target, ignoring movement within the deadzone.
+        let velocity = velocity.map_or(Vec2::ZERO, |x| x.0);
+        let goal = target_gt.translation().xy() + velocity * follow.look_ahead;
+        let delta = goal - pos;
+        let delta = delta.signum() * (delta.abs() - follow.deadzone).max(Vec2::ZERO);
+
+        // Exponential smoothing is independent of frame rate.
+        pos += delta * (Vec2::ONE - (-follow.rate * dt).exp());
+
+        if let Some(bounds) = follow.bounds {
+            // Keep the whole view within bounds, or center it if the bounds are too small.
+            let half_view = match projection {
+                Some(Projection::Orthographic(x)) => x.area.half_size(),
+                _ => Vec2::ZERO,
+            };
+            let min = bounds.min + half_view;
+            let max = bounds.max - half_view;
+            pos = vec2(
+                if min.x <= max.x {
+                    pos.x.clamp(min.x, max.x)
+                } else {
+                    bounds.center().x
+                },
+                if min.y <= max.y {
+                    pos.y.clamp(min.y, max.y)
+                } else {
+                    bounds.center().y
+                },
+            );
+        }
+
+        transform.translation = pos.extend(transform.translation.z);
+    }
+}
+
+/// Shakes the camera by an amount that decays over time.
+///
+/// The shake is applied through [`Offset`] and [`PostTransformSystems::Blend`], so it
+/// doesn't affect the underlying [`Transform`].
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+#[require(Offset)]
+pub struct CameraTrauma {
+    /// The current trauma, from `0.0` to `1.0`. Shake intensity is trauma squared.
+    pub trauma: f32,
+    /// The amount of trauma lost per second.
+    pub decay: f32,
+    /// The maximum translational offset, in world units.
+    pub max_offset: Vec2,
+    /// The maximum rotational offset, in radians.
+    pub max_rotation: f32,
+    /// How quickly the shake changes direction.
+    pub frequency: f32,
+    /// The current rotational offset, in radians.
+    rotation: f32,
+    elapsed: f32,
+}
+
+impl Configure for CameraTrauma {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            Update,
+            update_camera_trauma
+                .in_set(UpdateSystems::Update)
+                .in_set(PausableSystems),
+        );
+        app.add_systems(StateFlush, Pause.on_enter(reset_camera_shake));
+        app.add_systems(
+            PostUpdate,
+            apply_camera_trauma_rotation.in_set(PostTransformSystems::Blend),
+        );
+    }
+}
+
+impl Default for CameraTrauma {
+    fn default() -> Self {
+        Self {
+            trauma: 0.0,
+            decay: 1.2,
+            max_offset: Vec2::splat(24.0),
+            max_rotation: 0.05,
+            frequency: 18.0,
+            rotation: 0.0,
+            elapsed: 0.0,
+        }
+    }
+}
+
+impl CameraTrauma {
+    pub fn add(&mut self, trauma: f32) {
+        self.trauma = (self.trauma + trauma).clamp(0.0, 1.0);
+    }
+}
+
+/// Smooth pseudo-random noise in `-1.0..=1.0`, as a sum of incommensurate sine waves.
+fn shake_noise(seed: f32, t: f32) -> f32 {
+    let x = t + seed * 17.0;
+    ((x * 1.0).sin() + 0.5 * (x * 2.17).sin() + 0.25 * (x * 4.73).sin()) / 1.75
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn update_camera_trauma(
+    time: Res<Time>,
+    display_settings: Res<DisplaySettings>,
+    mut trauma_query: Query<(&mut CameraTrauma, &mut Offset)>,
+) {
+    let dt = time.delta_secs();
+    for (mut trauma, mut offset) in &mut trauma_query {
+        trauma.trauma = (trauma.trauma - trauma.decay * dt).max(0.0);
+        trauma.elapsed += dt;
+
+        let shake = trauma.trauma.powi(2) * display_settings.screen_shake;
+        let t = trauma.elapsed * trauma.frequency;
+        offset.0 = trauma.max_offset * shake * vec2(shake_noise(0.0, t), shake_noise(1.0, t));
+        trauma.rotation = trauma.max_rotation * shake * shake_noise(2.0, t);
+    }
+}
+
+/// Hold the camera still while paused, keeping the trauma for when it resumes.
+#[cfg_attr(feature = "native_dev", hot)]
+fn reset_camera_shake(mut trauma_query: Query<(&mut CameraTrauma, &mut Offset)>) {
+    for (mut trauma, mut offset) in &mut trauma_query {
+        offset.0 = Vec2::ZERO;
+        trauma.rotation = 0.0;
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn apply_camera_trauma_rotation(mut trauma_query: Query<(&CameraTrauma, &mut Transform)>) {
+    for (trauma, mut transform) in &mut trauma_query {
+        transform.rotate_z(trauma.rotation);
+    }
+}
+
+/// Zooms the camera's [`OrthographicProjection`] smoothly.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct CameraZoom {
+    /// The target [`OrthographicProjection::scale`].
+    pub scale: f32,
+    /// A rectangle to fit in view instead of using `scale`, if any.
+    pub fit: Option<Rect>,
+    /// The exponential decay rate towards the target zoom per second.
+    pub rate: f32,
+}
+
+impl Configure for CameraZoom {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(Update, apply_camera_zoom.in_set(UpdateSystems::Update));
+        app.add_systems(
+            PostUpdate,
+            snap_camera_to_pixel
+                .in_set(PostTransformSystems::Finish)
+                .run_if(|x: Res<DisplaySettings>| x.pixel_perfect),
+        );
+    }
+}
+
+impl Default for CameraZoom {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            fit: None,
+            rate: 6.0,
+        }
+    }
+}
+
+impl CameraZoom {
+    pub fn zoom_to(&mut self, scale: f32) {
+        self.scale = scale;
+        self.fit = None;
+    }
+
+    pub fn zoom_to_fit(&mut self, rect: Rect) {
+        self.fit = Some(rect);
+    }
+
+    /// Return to the default zoom.
+    pub fn reset(&mut self) {
+        self.zoom_to(1.0);
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn apply_camera_zoom(time: Res<Time>, mut camera_query: Query<(&CameraZoom, &mut Projection)>) {
+    let dt = time.delta_secs();
+    for (zoom, mut projection) in &mut camera_query {
+        let Projection::Orthographic(projection) = &mut *projection else {
+            continue;
+        };
+
+        let target = match zoom.fit {
+            Some(rect) => {
+                // The visible area at scale 1, for any `ScalingMode`.
+                cq!(projection.scale > 0.0);
+                let base_size = projection.area.size() / projection.scale;
+                cq!(base_size.min_element() > 0.0);
+                (rect.size() / base_size).max_element()
+            },
+            None => zoom.scale,
+        };
+        projection.scale = projection.scale.lerp(target, 1.0 - (-zoom.rate * dt).exp());
+    }
+}
+
+/// Round the camera's position to the nearest physical pixel.
+///
+/// Snaps from [`Transform`], since propagation only rewrites [`GlobalTransform`] when
+/// [`Transform`] changes. [`CameraZoom`] should only be used on root entities.
+#[cfg_attr(feature = "native_dev", hot)]
+fn snap_camera_to_pixel(
+    mut camera_query: Query<
+        (&mut GlobalTransform, &Transform, &Projection, &Camera),
+        With<CameraZoom>,
+    >,
+) {
+    for (mut gt, transform, projection, camera) in &mut camera_query {
+        let scale_factor = camera.target_scaling_factor().unwrap_or(1.0);
+        let pixel = cq!(units_per_logical_pixel(projection, camera)) / scale_factor;
+        let mut transform = *transform;
+        let pos = (transform.translation.xy() / pixel).round() * pixel;
         transform.translation = pos.extend(transform.translation.z);
+        *gt = transform.into();
     }
 }
 
@@ -98,15 +487,163 @@ fn apply_absolute_scale(
     mut scale_query: Query<(&mut Transform, &AbsoluteScale)>,
 ) {
     let (projection, camera) = r!(camera_query.get(camera_root.primary));
-    let projection = r!(match projection {
-        Projection::Orthographic(x) => Some(x),
-        _ => None,
-    });
-    let viewport_size = r!(camera.logical_viewport_size());
-    let units_per_pixel = projection.area.width() / viewport_size.x;
+    let units_per_pixel = r!(units_per_logical_pixel(projection, camera));
     let camera_scale_inverse = Vec2::splat(units_per_pixel).extend(1.0);
 
     for (mut transform, scale) in &mut scale_query {
         transform.scale = camera_scale_inverse * scale.0;
     }
 }
+
+/// The size of a logical pixel in world units, for an orthographic camera.
+fn units_per_logical_pixel(projection: &Projection, camera: &Camera) -> Option<f32> {
+    let Projection::Orthographic(projection) = projection else {
+        return None;
+    };
+    let viewport_size = camera.logical_viewport_size()?;
+    Some(projection.area.width() / viewport_size.x)
+}
+
+/// Rounds [`GlobalTransform`] translation to the primary camera's pixel grid after propagation.
+///
+/// Entities with [`AbsoluteScale`] are sized in logical pixels, so they snap to the logical
+/// pixel grid instead of the physical one.
+///
+/// Nested [`PixelSnap`] entities aren't supported: only the outermost one is snapped.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct PixelSnap;
+
+impl Configure for PixelSnap {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            PostUpdate,
+            apply_pixel_snap
+                .in_set(PostTransformSystems::Finish)
+                .after(snap_camera_to_pixel),
+        );
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn apply_pixel_snap(
+    camera_root: Res<CameraRoot>,
+    camera_query: Query<(&Projection, &Camera, &GlobalTransform), Without<PixelSnap>>,
+    parent_query: Query<&GlobalTransform, Without<PixelSnap>>,
+    mut snap_query: Query<
+        (
+            &mut GlobalTransform,
+            &Transform,
+            Option<&ChildOf>,
+            Has<AbsoluteScale>,
+        ),
+        With<PixelSnap>,
+    >,
+) {
+    let (projection, camera, camera_gt) = r!(camera_query.get(camera_root.primary));
+    let logical_pixel = r!(units_per_logical_pixel(projection, camera));
+    let logical_viewport = r!(camera.logical_viewport_size());
+    let scale_factor = camera.target_scaling_factor().unwrap_or(1.0);
+    let camera_pos = camera_gt.translation().xy();
+
+    for (mut gt, transform, child_of, is_absolute) in &mut snap_query {
+        let grid = if is_absolute {
+            PixelGrid::new(camera_pos, logical_pixel, logical_viewport)
+        } else {
+            PixelGrid::new(
+                camera_pos,
+                logical_pixel / scale_factor,
+                logical_viewport * scale_factor,
+            )
+        };
+        let parent_gt = match child_of {
+            Some(child_of) => Some(c!(parent_query.get(child_of.parent()))),
+            None => None,
+        };
+        *gt = grid.snap(transform, parent_gt);
+    }
+}
+
+/// A camera's pixel grid in world space.
+struct PixelGrid {
+    camera_pos: Vec2,
+    pixel: f32,
+    half_viewport: Vec2,
+}
+
+impl PixelGrid {
+    fn new(camera_pos: Vec2, pixel: f32, viewport: Vec2) -> Self {
+        Self {
+            camera_pos,
+            pixel,
+            half_viewport: viewport / 2.0,
+        }
+    }
+
+    /// The [`GlobalTransform`] for `transform` under `parent_gt`, with its translation
+    /// rounded to the grid.
+    ///
+    /// This recomputes from [`Transform`] rather than snapping the existing
+    /// [`GlobalTransform`], which propagation only rewrites when [`Transform`] changes, so
+    /// rounding errors don't accumulate as the grid moves.
+    fn snap(&self, transform: &Transform, parent_gt: Option<&GlobalTransform>) -> GlobalTransform {
+        let unsnapped = match parent_gt {
+            Some(parent_gt) => parent_gt.mul_transform(*transform),
+            None => GlobalTransform::from(*transform),
+        };
+        let mut affine = unsnapped.affine();
+
+        // Snap in screen space, where pixel edges are at integer coordinates.
+        let screen_pos = (Vec2::from(affine.translation.xy()) - self.camera_pos) / self.pixel
+            + self.half_viewport;
+        let pos = self.camera_pos + (screen_pos.round() - self.half_viewport) * self.pixel;
+        affine.translation = pos.extend(affine.translation.z).into();
+        affine.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::Affine3A;
+
+    use super::*;
+
+    #[test]
+    fn test_pixel_snap_static_entity() {
+        let transform = Transform::from_xyz(10.3, -7.7, 1.0);
+        let viewport = vec2(640.0, 360.0);
+
+        // Pan and zoom by sub-pixel amounts, as smooth follow and zoom do.
+        for frame in 0..1000 {
+            let camera_pos = vec2(0.037, -0.021) * frame as f32;
+            let pixel = 1.0 + 0.0013 * frame as f32;
+            let grid = PixelGrid::new(camera_pos, pixel, viewport);
+            let gt = grid.snap(&transform, None);
+
+            let error = gt.translation().xy() - transform.translation.xy();
+            assert!(
+                error.abs().max_element() <= pixel / 2.0 + 1e-4,
+                "{frame}: {error}"
+            );
+            assert_eq!(gt.translation().z, transform.translation.z);
+            // Snapping is stable for a fixed grid.
+            assert_eq!(grid.snap(&transform, None), gt);
+        }
+    }
+
+    #[test]
+    fn test_pixel_snap_keeps_shear() {
+        let parent_gt = GlobalTransform::from(Affine3A::from_cols_array(&[
+            1.0, 0.0, 0.0, 0.5, 1.0, 0.0, 0.0, 0.0, 1.0, 3.2, 4.6, 0.0,
+        ]));
+        let transform = Transform::from_xyz(1.0, 1.0, 0.0);
+        let grid = PixelGrid::new(Vec2::ZERO, 1.0, vec2(640.0, 360.0));
+
+        let gt = grid.snap(&transform, Some(&parent_gt));
+
+        let unsnapped = parent_gt.mul_transform(transform);
+        assert_eq!(gt.affine().matrix3, unsnapped.affine().matrix3);
+        assert_eq!(gt.translation().xy(), unsnapped.translation().xy().round());
+    }
+}
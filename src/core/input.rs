@@ -0,0 +1,204 @@
+use bevy::reflect::GetTypeRegistration;
+use bevy::reflect::Typed;
+use leafwing_input_manager::user_input::Buttonlike;
+use leafwing_input_manager::user_input::UserInput;
+
+use crate::prelude::*;
+
+/// An [`Actionlike`] with player-configurable [`Bindings`].
+pub trait Rebindable:
+    Actionlike + Copy + Reflect + FromReflect + TypePath + Typed + GetTypeRegistration
+{
+    /// The default bindings the player can change.
+    fn default_bindings() -> Vec<Binding<Self>>;
+
+    /// The context this action is read in.
+    fn context(&self) -> InputContext;
+
+    /// Bindings the player can't change, such as analog sticks.
+    fn fixed_input_map() -> InputMap<Self> {
+        InputMap::default()
+    }
+}
+
+/// Where an action is read. Actions in different contexts can share inputs without conflict.
+#[derive(Reflect, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum InputContext {
+    Gameplay,
+    Menu,
+}
+
+/// The player-configurable bindings for an action type, persisted with the settings.
+///
+/// The [`InputMap`] is rebuilt from these bindings whenever they change.
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct Bindings<A: Rebindable>(pub Vec<Binding<A>>);
+
+impl<A: Rebindable> Configure for Bindings<A> {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        let bindings = Self::default();
+        app.insert_resource(bindings.input_map());
+        app.insert_resource(bindings);
+        app.add_systems(
+            Update,
+            apply_bindings::<A>
+                .in_set(UpdateSystems::SyncEarly)
+                .run_if(resource_changed::<Self>),
+        );
+    }
+}
+
+impl<A: Rebindable> Default for Bindings<A> {
+    fn default() -> Self {
+        Self(A::default_bindings())
+    }
+}
+
+impl<A: Rebindable> Bindings<A> {
+    pub fn input_map(&self) -> InputMap<A> {
+        let mut input_map = A::fixed_input_map();
+
+        // Directional bindings are collected into virtual D-pads.
+        let mut key_dpads = HashMap::<A, [Option<KeyCode>; 4]>::default();
+        let mut button_dpads = HashMap::<A, [Option<GamepadButton>; 4]>::default();
+        for binding in &self.0 {
+            if let Some(direction) = binding.direction {
+                key_dpads.entry(binding.action).or_default()[direction as usize] = binding.key;
+                button_dpads.entry(binding.action).or_default()[direction as usize] =
+                    binding.button;
+                continue;
+            }
+
+            if let Some(key) = binding.key {
+                input_map.insert(binding.action, key);
+            }
+            if let Some(button) = binding.button {
+                input_map.insert(binding.action, button);
+            }
+        }
+        for (action, [up, down, left, right]) in key_dpads {
+            let (Some(up), Some(down), Some(left), Some(right)) = (up, down, left, right) else {
+                continue;
+            };
+            input_map.insert_dual_axis(action, VirtualDPad::new(up, down, left, right));
+        }
+        for (action, [up, down, left, right]) in button_dpads {
+            let (Some(up), Some(down), Some(left), Some(right)) = (up, down, left, right) else {
+                continue;
+            };
+            input_map.insert_dual_axis(action, VirtualDPad::new(up, down, left, right));
+        }
+
+        input_map
+    }
+
+    /// Whether an action read in `context` uses a key or gamepad button, including fixed inputs.
+    pub fn uses(&self, context: InputContext, input: &dyn Buttonlike) -> bool {
+        input_map_uses(&self.input_map(), context, input)
+    }
+
+    /// Bind a key to a binding, swapping with any other binding that already uses it.
+    pub fn bind_key(&mut self, index: usize, key: KeyCode) {
+        let old = r!(self.0.get(index)).key;
+        for binding in &mut self.0 {
+            if binding.key == Some(key) {
+                binding.key = old;
+            }
+        }
+        r!(self.0.get_mut(index)).key = Some(key);
+    }
+
+    /// Bind a gamepad button to a binding, swapping with any other binding that already uses it.
+    pub fn bind_button(&mut self, index: usize, button: GamepadButton) {
+        let old = r!(self.0.get(index)).button;
+        for binding in &mut self.0 {
+            if binding.button == Some(button) {
+                binding.button = old;
+            }
+        }
+        r!(self.0.get_mut(index)).button = Some(button);
+    }
+}
+
+/// Whether an action read in `context` uses a key or gamepad button in an [`InputMap`].
+pub fn input_map_uses<A: Rebindable>(
+    input_map: &InputMap<A>,
+    context: InputContext,
+    input: &dyn Buttonlike,
+) -> bool {
+    let buttonlike = input_map
+        .iter_buttonlike()
+        .flat_map(|(action, inputs)| inputs.iter().map(move |x| (action, x.decompose())));
+    let axislike = input_map
+        .iter_axislike()
+        .flat_map(|(action, inputs)| inputs.iter().map(move |x| (action, x.decompose())));
+    let dual_axislike = input_map
+        .iter_dual_axislike()
+        .flat_map(|(action, inputs)| inputs.iter().map(move |x| (action, x.decompose())));
+
+    buttonlike
+        .chain(axislike)
+        .chain(dual_axislike)
+        .filter(|(action, _)| action.context() == context)
+        .any(|(_, basic)| basic.inputs().iter().any(|x| **x == *input))
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn apply_bindings<A: Rebindable>(bindings: Res<Bindings<A>>, mut input_map: ResMut<InputMap<A>>) {
+    *input_map = bindings.input_map();
+}
+
+/// A rebindable key and gamepad button for an action, or for one direction of a dual-axis action.
+#[derive(Reflect, Clone, Debug)]
+pub struct Binding<A: Rebindable> {
+    pub action: A,
+    pub direction: Option<BindingDirection>,
+    pub key: Option<KeyCode>,
+    pub button: Option<GamepadButton>,
+}
+
+impl<A: Rebindable> Binding<A> {
+    pub fn new(action: A, key: KeyCode, button: GamepadButton) -> Self {
+        Self {
+            action,
+            direction: None,
+            key: Some(key),
+            button: Some(button),
+        }
+    }
+
+    /// Bind one direction of a dual-axis action.
+    pub fn with_direction(mut self, direction: BindingDirection) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// A human-readable name for the binding, e.g. "Move up".
+    pub fn label(&self) -> String {
+        let action = format!("{:?}", self.action);
+        match self.direction {
+            Some(direction) => format!("{action} {}", format!("{direction:?}").to_lowercase()),
+            None => action,
+        }
+    }
+}
+
+/// A direction of a dual-axis action, in [`VirtualDPad`] order.
+#[derive(Reflect, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum BindingDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// A human-readable name for a key, e.g. "W" instead of "KeyW".
+pub fn key_name(key: KeyCode) -> String {
+    let name = format!("{key:?}");
+    name.strip_prefix("Key")
+        .or_else(|| name.strip_prefix("Digit"))
+        .unwrap_or(&name)
+        .to_string()
+}
@@ -5,9 +5,11 @@ pub mod audio;
 pub mod camera;
 #[cfg(feature = "dev")]
 pub mod dev;
+pub mod input;
 pub mod pause;
 pub mod physics;
 pub mod state;
+pub mod time;
 pub mod window;
 
 use bevy::audio::AudioPlugin;
@@ -15,7 +17,7 @@ use bevy::audio::AudioPlugin;
 use crate::prelude::*;
 
 pub(super) fn plugin(app: &mut App) {
-    app.configure::<UpdateSystems>();
+    app.configure::<(UpdateSystems, FixedUpdateSystems)>();
 
     // Add Bevy plugins.
     app.add_plugins(
@@ -37,6 +39,7 @@ pub(super) fn plugin(app: &mut App) {
         dev::plugin,
         pause::plugin,
         physics::plugin,
+        time::plugin,
     ));
 }
 
@@ -76,3 +79,26 @@ impl Configure for UpdateSystems {
         );
     }
 }
+
+/// Deterministic game logic steps for the [`FixedUpdate`] schedule.
+///
+/// Input is buffered in [`UpdateSystems::RecordInput`] and consumed here, so identical input
+/// produces identical simulation regardless of frame rate.
+#[derive(SystemSet, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum FixedUpdateSystems {
+    /// Consume input buffered from [`Update`].
+    RecordInput,
+    /// Step game logic.
+    Update,
+    /// Handle events emitted this step.
+    HandleEvents,
+}
+
+impl Configure for FixedUpdateSystems {
+    fn configure(app: &mut App) {
+        app.configure_sets(
+            FixedUpdate,
+            (Self::RecordInput, Self::Update, Self::HandleEvents).chain(),
+        );
+    }
+}
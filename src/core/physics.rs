@@ -1,7 +1,12 @@
 use crate::prelude::*;
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_plugins(PhysicsPlugins::default().with_length_unit(PIXELS_PER_METER));
+    app.add_plugins(
+        PhysicsPlugins::default()
+            .with_length_unit(PIXELS_PER_METER)
+            // Smooth out rendered transforms between fixed timesteps.
+            .set(PhysicsInterpolationPlugin::interpolate_all()),
+    );
     app.insert_resource(Gravity::ZERO);
 
     app.add_systems(StateFlush, Pause.on_edge(unpause_physics, pause_physics));
@@ -0,0 +1,119 @@
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<TimeDilation>();
+}
+
+/// A stack of time scales for dramatic moments like hit-stop and slow-motion.
+///
+/// The combined scale is applied to [`Time<Virtual>`], which also dilates [`Time<Physics>`]
+/// because physics steps in [`FixedPostUpdate`]. Systems that should stay responsive during
+/// dilation (e.g. UI animations) should use [`Time<Real>`] instead.
+///
+/// Dilation is suspended while the game is paused.
+#[derive(Resource, Reflect, Default, Debug)]
+#[reflect(Resource)]
+pub struct TimeDilation(Vec<Dilation>);
+
+impl Configure for TimeDilation {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+        app.add_systems(
+            Update,
+            (
+                tick_time_dilation.in_set(PausableSystems),
+                apply_time_dilation,
+            )
+                .chain()
+                .in_set(UpdateSystems::SyncLate),
+        );
+    }
+}
+
+/// The time scale for [`TimeDilation::slow_motion`].
+pub const SLOW_MOTION_SCALE: f32 = 0.3;
+
+impl TimeDilation {
+    /// Push a time scale onto the stack for a duration in real time.
+    pub fn push(&mut self, scale: f32, duration: Duration) {
+        self.0.push(Dilation {
+            scale: scale.max(0.0),
+            remaining: duration,
+        });
+    }
+
+    /// Freeze time for a duration.
+    pub fn hit_stop(&mut self, duration: Duration) {
+        self.push(0.0, duration);
+    }
+
+    /// Slow time down to [`SLOW_MOTION_SCALE`] for a duration.
+    pub fn slow_motion(&mut self, duration: Duration) {
+        self.push(SLOW_MOTION_SCALE, duration);
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// The combined time scale of the stack.
+    pub fn scale(&self) -> f32 {
+        self.0.iter().map(|x| x.scale).product()
+    }
+
+    /// Advance the stack by a duration in real time, removing any time scales that expire.
+    pub fn tick(&mut self, delta: Duration) {
+        self.0.retain_mut(|x| {
+            x.remaining = x.remaining.saturating_sub(delta);
+            !x.remaining.is_zero()
+        });
+    }
+}
+
+#[derive(Reflect, Copy, Clone, Debug)]
+struct Dilation {
+    scale: f32,
+    /// The real time until this time scale expires.
+    remaining: Duration,
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn tick_time_dilation(time: Res<Time<Real>>, mut dilation: ResMut<TimeDilation>) {
+    dilation.tick(time.delta());
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn apply_time_dilation(
+    pause: CurrentRef<Pause>,
+    dilation: Res<TimeDilation>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    let scale = if pause.is_enabled() {
+        1.0
+    } else {
+        dilation.scale()
+    };
+    if virtual_time.relative_speed() != scale {
+        virtual_time.set_relative_speed(scale);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stack_restores() {
+        let mut dilation = TimeDilation::default();
+        dilation.slow_motion(Duration::from_millis(1000));
+        dilation.hit_stop(Duration::from_millis(100));
+        assert_eq!(dilation.scale(), 0.0);
+
+        dilation.tick(Duration::from_millis(100));
+        assert_eq!(dilation.scale(), SLOW_MOTION_SCALE);
+
+        dilation.tick(Duration::from_millis(900));
+        assert_eq!(dilation.scale(), 1.0);
+    }
+}
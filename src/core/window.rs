@@ -1,6 +1,8 @@
 use bevy::window::ExitCondition;
+use bevy::window::MonitorSelection;
 use bevy::window::PresentMode;
 use bevy::window::PrimaryWindow;
+use bevy::window::VideoModeSelection;
 use bevy::window::WindowMode;
 
 use crate::prelude::*;
@@ -17,7 +19,12 @@ pub(super) fn plugin(app: &mut App) {
         ..default()
     });
 
-    app.configure::<(WindowRoot, ConfigHandle<WindowConfig>, WindowReady)>();
+    app.configure::<(
+        WindowRoot,
+        ConfigHandle<WindowConfig>,
+        WindowReady,
+        DisplaySettings,
+    )>();
 }
 
 #[derive(Resource, Reflect)]
@@ -48,8 +55,6 @@ impl FromWorld for WindowRoot {
 #[serde(deny_unknown_fields)]
 pub struct WindowConfig {
     pub title: String,
-    pub window_mode: WindowMode,
-    pub present_mode: PresentMode,
 }
 
 impl Config for WindowConfig {
@@ -61,8 +66,6 @@ impl Config for WindowConfig {
         let window_root = r!(world.get_resource::<WindowRoot>());
         let mut window = r!(world.get_mut::<Window>(window_root.primary));
         window.title.clone_from(&self.title);
-        window.mode = self.window_mode;
-        window.present_mode = self.present_mode;
     }
 }
 
@@ -83,3 +86,79 @@ impl Configure for WindowReady {
 fn show_window(window_root: Res<WindowRoot>, mut window_query: Query<&mut Window>) {
     r!(window_query.get_mut(window_root.primary)).visible = true;
 }
+
+/// Player-configurable display settings, persisted with the other settings.
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct DisplaySettings {
+    pub window_mode: DisplayMode,
+    pub vsync: bool,
+    pub ui_scale: f32,
+    /// A multiplier for screen shake intensity, for accessibility.
+    pub screen_shake: f32,
+    /// Render at a fixed virtual resolution, scaled up to the window by an integer factor.
+    pub pixel_perfect: bool,
+}
+
+impl Default for DisplaySettings {
+    fn default() -> Self {
+        Self {
+            window_mode: DisplayMode::Windowed,
+            vsync: true,
+            ui_scale: 1.0,
+            screen_shake: 1.0,
+            pixel_perfect: false,
+        }
+    }
+}
+
+impl Configure for DisplaySettings {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+        app.add_systems(
+            Update,
+            apply_display_settings
+                .run_if(resource_changed::<Self>)
+                .in_set(UpdateSystems::Update),
+        );
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn apply_display_settings(
+    display_settings: Res<DisplaySettings>,
+    window_root: Res<WindowRoot>,
+    mut window_query: Query<&mut Window>,
+    mut ui_scale: ResMut<UiScale>,
+) {
+    ui_scale.0 = display_settings.ui_scale;
+
+    let mut window = r!(window_query.get_mut(window_root.primary));
+    window.mode = display_settings.window_mode.into();
+    window.present_mode = if display_settings.vsync {
+        PresentMode::AutoVsync
+    } else {
+        PresentMode::AutoNoVsync
+    };
+}
+
+#[derive(Reflect, Copy, Clone, Default, Eq, PartialEq, Debug)]
+pub enum DisplayMode {
+    #[default]
+    Windowed,
+    Borderless,
+    Fullscreen,
+}
+
+impl From<DisplayMode> for WindowMode {
+    fn from(value: DisplayMode) -> Self {
+        match value {
+            DisplayMode::Windowed => Self::Windowed,
+            DisplayMode::Borderless => Self::BorderlessFullscreen(MonitorSelection::Current),
+            DisplayMode::Fullscreen => {
+                Self::Fullscreen(MonitorSelection::Current, VideoModeSelection::Current)
+            },
+        }
+    }
+}
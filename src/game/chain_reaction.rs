@@ -0,0 +1,520 @@
+use crate::animation::particle::particle_burst;
+use crate::core::audio::SpatialFalloff;
+use crate::core::audio::spatial_sfx;
+use crate::core::camera::CameraRoot;
+use crate::core::camera::CameraTrauma;
+use crate::core::time::TimeDilation;
+use crate::prelude::*;
+use crate::screen::Screen;
+use crate::screen::gameplay::GameplayAssets;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<(
+        ChainReaction,
+        Explosive,
+        ChainTrigger,
+        PendingDetonation,
+        Detonate,
+        ChainHop,
+    )>();
+}
+
+/// Bookkeeping for a chain reaction in progress.
+///
+/// Despawned once none of its detonations are pending.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component)]
+pub struct ChainReaction {
+    /// The number of detonations so far.
+    pub length: u32,
+    /// The deepest hop so far.
+    pub depth: u32,
+}
+
+impl Configure for ChainReaction {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            FixedUpdate,
+            finish_chain_reactions
+                .after(handle_detonations)
+                .in_set(FixedUpdateSystems::HandleEvents)
+                .in_set(PausableSystems),
+        );
+    }
+}
+
+/// A new chain reaction, to be referenced by the [`Detonate`] events that start it.
+pub fn chain_reaction() -> impl Bundle {
+    (
+        Name::new("ChainReaction"),
+        ChainReaction::default(),
+        DespawnOnExitState::<Screen>::default(),
+    )
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn finish_chain_reactions(
+    mut commands: Commands,
+    chain_query: Query<(Entity, &ChainReaction)>,
+    pending_query: Query<&PendingDetonation>,
+) {
+    for (entity, chain) in &chain_query {
+        cq!(!pending_query.iter().any(|x| x.chain == entity));
+        info!(
+            "Chain reaction finished: length {}, depth {}",
+            chain.length, chain.depth,
+        );
+        commands.entity(entity).despawn();
+    }
+}
+
+/// An entity that detonates its neighbors within a radius when detonated.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct Explosive {
+    /// The radius within which neighbors will be detonated.
+    pub radius: f32,
+    /// The delay in seconds between being reached by a chain reaction and detonating.
+    pub delay: f32,
+}
+
+impl Configure for Explosive {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+    }
+}
+
+/// An [`Explosive`] that starts a new chain reaction when something collides with it.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+#[require(CollisionEventsEnabled)]
+pub struct ChainTrigger;
+
+impl Configure for ChainTrigger {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            FixedUpdate,
+            trigger_on_collision
+                .in_set(FixedUpdateSystems::Update)
+                .in_set(PausableSystems),
+        );
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn trigger_on_collision(
+    mut commands: Commands,
+    mut collision_events: EventReader<CollisionStarted>,
+    mut detonate_events: EventWriter<Detonate>,
+    trigger_query: Query<
+        (),
+        (
+            With<ChainTrigger>,
+            With<Explosive>,
+            Without<PendingDetonation>,
+        ),
+    >,
+) {
+    // A trigger can collide with multiple entities in one step, but should only start one chain.
+    let mut triggered = HashSet::new();
+    for &CollisionStarted(a, b) in collision_events.read() {
+        for entity in [a, b] {
+            cq!(trigger_query.contains(entity) && triggered.insert(entity));
+            let chain = commands.spawn(chain_reaction()).id();
+            detonate_events.write(Detonate::root(entity, chain));
+        }
+    }
+}
+
+/// An [`Explosive`] that has been reached by a chain reaction and will detonate soon.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct PendingDetonation {
+    pub chain: Entity,
+    pub depth: u32,
+    pub timer: Timer,
+}
+
+impl Configure for PendingDetonation {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            FixedUpdate,
+            tick_pending_detonations
+                .in_set(FixedUpdateSystems::Update)
+                .in_set(PausableSystems),
+        );
+    }
+}
+
+impl PendingDetonation {
+    fn new(chain: Entity, depth: u32, delay: f32) -> Self {
+        Self {
+            chain,
+            depth,
+            timer: Timer::from_seconds(delay, TimerMode::Once),
+        }
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn tick_pending_detonations(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut detonate_events: EventWriter<Detonate>,
+    mut pending_query: Query<(Entity, &mut PendingDetonation)>,
+) {
+    for (entity, mut pending) in &mut pending_query {
+        cq!(pending.timer.tick(time.delta()).finished());
+        commands.entity(entity).remove::<PendingDetonation>();
+        detonate_events.write(Detonate {
+            entity,
+            chain: pending.chain,
+            depth: pending.depth,
+        });
+    }
+}
+
+/// A request to detonate an [`Explosive`] as part of a [`ChainReaction`].
+#[derive(Event, Reflect, Copy, Clone, Debug)]
+pub struct Detonate {
+    pub entity: Entity,
+    pub chain: Entity,
+    pub depth: u32,
+}
+
+impl Configure for Detonate {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_event::<Self>();
+        app.add_systems(
+            FixedUpdate,
+            handle_detonations
+                .in_set(FixedUpdateSystems::HandleEvents)
+                .in_set(PausableSystems),
+        );
+    }
+}
+
+impl Detonate {
+    /// Detonate an entity as the first hop of a chain reaction.
+    pub fn root(entity: Entity, chain: Entity) -> Self {
+        Self {
+            entity,
+            chain,
+            depth: 0,
+        }
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+pub fn handle_detonations(
+    mut commands: Commands,
+    mut detonate_events: EventReader<Detonate>,
+    mut hop_events: EventWriter<ChainHop>,
+    mut chain_query: Query<&mut ChainReaction>,
+    explosive_query: Query<(Entity, &Position, &Explosive)>,
+    pending_query: Query<(), With<PendingDetonation>>,
+) {
+    let mut detonated = HashSet::new();
+    // Neighbors reached earlier in this batch, whose `PendingDetonation` isn't inserted yet.
+    let mut queued = HashSet::new();
+    for event in detonate_events.read() {
+        cq!(detonated.insert(event.entity));
+        let (_, &Position(position), explosive) = cq!(explosive_query.get(event.entity));
+        let mut chain = cq!(chain_query.get_mut(event.chain));
+        chain.length += 1;
+        chain.depth = chain.depth.max(event.depth);
+
+        hop_events.write(ChainHop {
+            entity: event.entity,
+            chain: event.chain,
+            position,
+            depth: event.depth,
+            length: chain.length,
+        });
+        commands.entity(event.entity).despawn();
+
+        // Spread to neighbors within the radius.
+        for (neighbor, neighbor_position, neighbor_explosive) in &explosive_query {
+            cq!(!detonated.contains(&neighbor) && !pending_query.contains(neighbor));
+            cq!(neighbor_position.distance(position) <= explosive.radius);
+            cq!(queued.insert(neighbor));
+            commands.entity(neighbor).try_insert(PendingDetonation::new(
+                event.chain,
+                event.depth + 1,
+                neighbor_explosive.delay,
+            ));
+        }
+    }
+}
+
+/// A report of a single detonation in a [`ChainReaction`], for scoring and effects.
+#[derive(Event, Reflect, Copy, Clone, Debug)]
+pub struct ChainHop {
+    pub entity: Entity,
+    pub chain: Entity,
+    pub position: Vec2,
+    /// The number of hops from the start of the chain reaction.
+    pub depth: u32,
+    /// The number of detonations in the chain reaction so far, including this one.
+    pub length: u32,
+}
+
+impl Configure for ChainHop {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_event::<Self>();
+        app.add_systems(
+            Update,
+            (
+                play_explosion_sfx,
+                spawn_explosion_particles,
+                add_explosion_trauma,
+                dilate_explosion_time,
+            )
+                .in_set(UpdateSystems::HandleEvents)
+                .in_set(PausableSystems),
+        );
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn play_explosion_sfx(
+    mut commands: Commands,
+    mut hop_events: EventReader<ChainHop>,
+    assets: Res<GameplayAssets>,
+) {
+    for hop in hop_events.read() {
+        // Later hops in a chain reaction sound deeper.
+        let speed = 0.6 / (1.0 + 0.05 * hop.depth as f32);
+        commands
+            .spawn(spatial_sfx(
+                assets.sfx_explosion.clone(),
+                hop.position,
+                SpatialFalloff::default(),
+            ))
+            .entry::<PlaybackSettings>()
+            .and_modify(move |mut x| x.speed = speed);
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn spawn_explosion_particles(mut commands: Commands, mut hop_events: EventReader<ChainHop>) {
+    for hop in hop_events.read() {
+        commands.spawn(particle_burst("explosion", hop.position));
+    }
+}
+
+/// The camera trauma added per detonation.
+const EXPLOSION_TRAUMA: f32 = 0.2;
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn add_explosion_trauma(
+    mut hop_events: EventReader<ChainHop>,
+    camera_root: Res<CameraRoot>,
+    mut trauma_query: Query<&mut CameraTrauma>,
+) {
+    let hops = hop_events.read().count();
+    rq!(hops > 0);
+    let mut trauma = r!(trauma_query.get_mut(camera_root.primary));
+    trauma.add(EXPLOSION_TRAUMA * hops as f32);
+}
+
+/// The hit-stop at the start of a chain reaction.
+const CHAIN_START_HIT_STOP: Duration = Duration::from_millis(80);
+
+/// The chain reaction length that triggers slow-motion.
+const SLOW_MOTION_CHAIN_LENGTH: u32 = 8;
+
+/// The duration of slow-motion for a long chain reaction.
+const SLOW_MOTION_DURATION: Duration = Duration::from_millis(1200);
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn dilate_explosion_time(
+    mut hop_events: EventReader<ChainHop>,
+    mut dilation: ResMut<TimeDilation>,
+) {
+    for hop in hop_events.read() {
+        if hop.depth == 0 && hop.length == 1 {
+            dilation.hit_stop(CHAIN_START_HIT_STOP);
+        }
+        if hop.length == SLOW_MOTION_CHAIN_LENGTH {
+            dilation.slow_motion(SLOW_MOTION_DURATION);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::event::EventCursor;
+    use bevy::time::TimePlugin;
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_event::<Detonate>();
+        app.add_event::<ChainHop>();
+        app.insert_resource(Time::<()>::default());
+        app.add_systems(
+            Update,
+            (
+                tick_pending_detonations,
+                handle_detonations,
+                finish_chain_reactions,
+            )
+                .chain(),
+        );
+        app
+    }
+
+    fn explosive(app: &mut App, x: f32, delay: f32) -> Entity {
+        app.world_mut()
+            .spawn((
+                Explosive {
+                    radius: 15.0,
+                    delay,
+                },
+                Position::from_xy(x, 0.0),
+            ))
+            .id()
+    }
+
+    /// Advance time and run one update, returning the hops reported.
+    fn step(app: &mut App, cursor: &mut EventCursor<ChainHop>, secs: f32) -> Vec<ChainHop> {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(secs));
+        app.update();
+        cursor
+            .read(app.world().resource::<Events<ChainHop>>())
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn test_propagation() {
+        let mut app = app();
+        let a = explosive(&mut app, 0.0, 0.1);
+        let b = explosive(&mut app, 10.0, 0.25);
+        let c = explosive(&mut app, 20.0, 0.1);
+        let far = explosive(&mut app, 100.0, 0.1);
+        let chain = app.world_mut().spawn(ChainReaction::default()).id();
+        app.world_mut().send_event(Detonate::root(a, chain));
+        let mut cursor = EventCursor::default();
+
+        let hops = step(&mut app, &mut cursor, 0.0);
+        assert_eq!(hops.len(), 1);
+        assert_eq!((hops[0].entity, hops[0].depth, hops[0].length), (a, 0, 1));
+
+        // `b` waits for its own delay before detonating.
+        assert!(step(&mut app, &mut cursor, 0.1).is_empty());
+        assert!(step(&mut app, &mut cursor, 0.1).is_empty());
+        let hops = step(&mut app, &mut cursor, 0.1);
+        assert_eq!(hops.len(), 1);
+        assert_eq!((hops[0].entity, hops[0].depth, hops[0].length), (b, 1, 2));
+
+        let hops = step(&mut app, &mut cursor, 0.1);
+        assert_eq!(hops.len(), 1);
+        assert_eq!((hops[0].entity, hops[0].depth, hops[0].length), (c, 2, 3));
+
+        // `far` is out of range, so the chain reaction ends.
+        assert!(step(&mut app, &mut cursor, 0.1).is_empty());
+        assert!(app.world().get_entity(far).is_ok());
+        assert!(app.world().get_entity(chain).is_err());
+    }
+
+    #[test]
+    fn test_shared_neighbor() {
+        let mut app = app();
+        let a = explosive(&mut app, 0.0, 0.1);
+        let b = explosive(&mut app, 10.0, 0.1);
+        let c = explosive(&mut app, 20.0, 0.1);
+        let chain = app.world_mut().spawn(ChainReaction::default()).id();
+        app.world_mut().send_event(Detonate::root(a, chain));
+        app.world_mut().send_event(Detonate {
+            entity: c,
+            chain,
+            depth: 3,
+        });
+        let mut cursor = EventCursor::default();
+
+        assert_eq!(step(&mut app, &mut cursor, 0.0).len(), 2);
+        // The first detonation to reach `b` decides its depth.
+        let pending = app.world().get::<PendingDetonation>(b).unwrap();
+        assert_eq!(pending.depth, 1);
+
+        let hops = step(&mut app, &mut cursor, 0.1);
+        assert_eq!(hops.len(), 1);
+        assert_eq!((hops[0].entity, hops[0].depth, hops[0].length), (b, 1, 3));
+        assert!(app.world().get_entity(chain).is_err());
+    }
+
+    /// The hops of a chain reaction, by the fixed timestep they happened on.
+    #[derive(Resource, Default)]
+    struct FixedHops(Vec<(Duration, Entity, u32, u32, Vec2)>);
+
+    fn record_fixed_hops(
+        time: Res<Time<Fixed>>,
+        mut hop_events: EventReader<ChainHop>,
+        mut hops: ResMut<FixedHops>,
+    ) {
+        for hop in hop_events.read() {
+            hops.0.push((
+                time.elapsed(),
+                hop.entity,
+                hop.depth,
+                hop.length,
+                hop.position,
+            ));
+        }
+    }
+
+    /// Run a chain reaction for a few seconds with [`Update`] at the given rate.
+    fn run_chain_reaction(update_hz: f64) -> Vec<(Duration, Entity, u32, u32, Vec2)> {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+            1.0 / update_hz,
+        )));
+        app.add_event::<Detonate>();
+        app.add_event::<ChainHop>();
+        app.init_resource::<FixedHops>();
+        app.add_systems(
+            FixedUpdate,
+            (
+                tick_pending_detonations,
+                handle_detonations,
+                finish_chain_reactions,
+                record_fixed_hops,
+            )
+                .chain(),
+        );
+        let explosives = [0.0, 10.0, 20.0, 25.0, 40.0, 100.0]
+            .into_iter()
+            .enumerate()
+            .map(|(i, x)| explosive(&mut app, x, 0.05 + 0.03 * i as f32))
+            .collect::<Vec<_>>();
+        let chain = app.world_mut().spawn(ChainReaction::default()).id();
+        app.world_mut()
+            .entity_mut(explosives[0])
+            .insert(PendingDetonation::new(chain, 0, 0.0));
+
+        for _ in 0..(3.0 * update_hz) as usize {
+            app.update();
+        }
+        core::mem::take(&mut app.world_mut().resource_mut::<FixedHops>().0)
+    }
+
+    #[test]
+    fn test_frame_rate_independence() {
+        let hops = run_chain_reaction(60.0);
+        assert_eq!(hops.len(), 5);
+        assert_eq!(run_chain_reaction(24.0), hops);
+        assert_eq!(run_chain_reaction(144.0), hops);
+    }
+}
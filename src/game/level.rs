@@ -0,0 +1,490 @@
+use crate::animation::tween::OffsetLens;
+use crate::animation::tween::Tween;
+use crate::animation::tween::TweenFinished;
+use crate::core::camera::PixelSnap;
+use crate::game::chain_reaction::ChainHop;
+use crate::game::chain_reaction::ChainReaction;
+use crate::game::chain_reaction::ChainTrigger;
+use crate::game::chain_reaction::Explosive;
+use crate::game::chain_reaction::handle_detonations;
+use crate::game::player::Detonator;
+use crate::game::player::player;
+use crate::prelude::*;
+use crate::screen::Screen;
+use crate::screen::transition::Transition;
+use crate::screen::transition::TransitionStyle;
+use crate::screen::transition::transition_to;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<(
+        ConfigHandle<LevelConfig>,
+        LevelAssets,
+        CurrentLevel,
+        IsLevel,
+        IsBomb,
+        LevelStats,
+        ScorePopup,
+        LevelOutcome,
+    )>();
+}
+
+#[derive(Asset, Reflect, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LevelConfig {
+    pub levels: Vec<LevelData>,
+}
+
+impl Config for LevelConfig {
+    const FILE: &'static str = "levels.ron";
+    const FOLDER: &'static str = "levels";
+
+    fn on_load(&self, world: &mut World) {
+        let asset_server = r!(world.get_resource::<AssetServer>()).clone();
+        let handles = self
+            .levels
+            .iter()
+            .flat_map(|x| x.load_assets(&asset_server))
+            .collect();
+        world.insert_resource(LevelAssets(handles));
+    }
+
+    fn count_progress(&self, asset_server: &AssetServer) -> Progress {
+        let mut done = 0;
+        let mut total = 0;
+        for path in self.levels.iter().flat_map(LevelData::asset_paths) {
+            total += 1;
+            done += asset_server
+                .get_path_id(path)
+                .is_some_and(|id| asset_server.is_loaded_with_dependencies(id))
+                as u32;
+        }
+
+        Progress { done, total }
+    }
+}
+
+#[derive(Reflect, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct LevelData {
+    pub name: String,
+    #[serde(default)]
+    pub music: Option<String>,
+    /// The area for the camera to stay within while following the player.
+    #[serde(default)]
+    pub camera_bounds: Option<Rect>,
+    pub player: Vec2,
+    pub charges: u32,
+    pub win_condition: WinCondition,
+    pub placements: Vec<Placement>,
+}
+
+impl LevelData {
+    /// The paths of all assets this level references.
+    fn asset_paths(&self) -> impl Iterator<Item = &str> {
+        self.music
+            .iter()
+            .chain(self.placements.iter().filter_map(|x| x.sprite.as_ref()))
+            .map(String::as_str)
+    }
+
+    fn load_assets(&self, asset_server: &AssetServer) -> Vec<UntypedHandle> {
+        self.music
+            .iter()
+            .map(|path| asset_server.load::<AudioSource>(path).untyped())
+            .chain(
+                self.placements
+                    .iter()
+                    .filter_map(|x| x.sprite.as_ref())
+                    .map(|path| asset_server.load::<Image>(path).untyped()),
+            )
+            .collect()
+    }
+}
+
+/// An entity to spawn in a level.
+#[derive(Reflect, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct Placement {
+    pub kind: PlacementKind,
+    pub position: Vec2,
+    pub body: BodyConfig,
+    #[serde(default)]
+    pub sprite: Option<String>,
+}
+
+#[derive(Reflect, Serialize, Deserialize, Copy, Clone)]
+pub enum PlacementKind {
+    /// An [`Explosive`] that counts towards the [`WinCondition`].
+    Bomb { radius: f32, delay: f32 },
+    /// A bomb that starts a chain reaction when something collides with it.
+    Trigger { radius: f32, delay: f32 },
+    /// An obstacle.
+    Wall,
+}
+
+#[derive(Reflect, Serialize, Deserialize, Copy, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct BodyConfig {
+    pub rigid_body: BodyKind,
+    pub collider: ColliderShape,
+}
+
+#[derive(Reflect, Serialize, Deserialize, Copy, Clone)]
+pub enum BodyKind {
+    Static,
+    Dynamic,
+    Kinematic,
+}
+
+impl From<BodyKind> for RigidBody {
+    fn from(value: BodyKind) -> Self {
+        match value {
+            BodyKind::Static => Self::Static,
+            BodyKind::Dynamic => Self::Dynamic,
+            BodyKind::Kinematic => Self::Kinematic,
+        }
+    }
+}
+
+#[derive(Reflect, Serialize, Deserialize, Copy, Clone)]
+pub enum ColliderShape {
+    Circle(f32),
+    Rectangle(f32, f32),
+}
+
+impl ColliderShape {
+    fn size(self) -> Vec2 {
+        match self {
+            Self::Circle(radius) => Vec2::splat(2.0 * radius),
+            Self::Rectangle(width, height) => vec2(width, height),
+        }
+    }
+}
+
+impl From<ColliderShape> for Collider {
+    fn from(value: ColliderShape) -> Self {
+        match value {
+            ColliderShape::Circle(radius) => Self::circle(radius),
+            ColliderShape::Rectangle(width, height) => Self::rectangle(width, height),
+        }
+    }
+}
+
+#[derive(Reflect, Serialize, Deserialize, Copy, Clone, Debug)]
+pub enum WinCondition {
+    /// Destroy every bomb.
+    DestroyAll,
+    /// Destroy at least this many bombs.
+    DestroyAtLeast(u32),
+    /// Cause a chain reaction of at least this length.
+    ChainLength(u32),
+}
+
+impl WinCondition {
+    fn is_met(self, bombs_left: usize, stats: &LevelStats) -> bool {
+        match self {
+            Self::DestroyAll => bombs_left == 0,
+            Self::DestroyAtLeast(n) => stats.detonations >= n,
+            Self::ChainLength(n) => stats.longest_chain >= n,
+        }
+    }
+}
+
+/// Strong handles to the assets referenced by [`LevelConfig`].
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+pub struct LevelAssets(#[reflect(ignore)] pub Vec<UntypedHandle>);
+
+impl Configure for LevelAssets {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+        app.add_systems(
+            Update,
+            state!(Screen::Title | Screen::Loading)
+                .on_update(LevelConfig::progress.track_progress::<BevyState<Screen>>()),
+        );
+    }
+}
+
+/// The index of the level to play in [`LevelConfig::levels`].
+#[derive(Resource, Reflect, Copy, Clone, Default, Debug)]
+#[reflect(Resource)]
+pub struct CurrentLevel(pub usize);
+
+impl Configure for CurrentLevel {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+    }
+}
+
+/// The root entity of the current level.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct IsLevel;
+
+impl Configure for IsLevel {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+    }
+}
+
+/// A level and its content, to be despawned when exiting [`Screen::Gameplay`].
+pub fn level(data: &LevelData, asset_server: &AssetServer) -> impl Bundle {
+    let placements = data
+        .placements
+        .iter()
+        .map(|x| {
+            (
+                x.clone(),
+                x.sprite.as_ref().map(|path| asset_server.load(path)),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    (
+        Name::new(format!("Level(\"{}\")", data.name)),
+        IsLevel,
+        Transform::default(),
+        Visibility::default(),
+        Children::spawn((
+            Spawn(player(data.player, data.charges)),
+            SpawnWith(move |parent: &mut ChildSpawner| {
+                for (placement, image) in placements {
+                    spawn_placement(parent, placement, image);
+                }
+            }),
+        )),
+    )
+}
+
+fn spawn_placement(parent: &mut ChildSpawner, placement: Placement, image: Option<Handle<Image>>) {
+    let size = placement.body.collider.size();
+    let (sprite, color) = match image {
+        Some(image) => (
+            Sprite {
+                image,
+                custom_size: Some(size),
+                ..default()
+            },
+            ThemeColor::White,
+        ),
+        None => (
+            Sprite::from_color(Color::WHITE, size),
+            match placement.kind {
+                PlacementKind::Bomb { .. } => ThemeColor::Bomb,
+                PlacementKind::Trigger { .. } => ThemeColor::Trigger,
+                PlacementKind::Wall => ThemeColor::Wall,
+            },
+        ),
+    };
+
+    let mut entity = parent.spawn((
+        Transform::from_translation(placement.position.extend(0.0)),
+        sprite,
+        color.set::<Sprite>(),
+        PixelSnap,
+        RigidBody::from(placement.body.rigid_body),
+        Collider::from(placement.body.collider),
+    ));
+    match placement.kind {
+        PlacementKind::Bomb { radius, delay } => {
+            entity.insert((Name::new("Bomb"), IsBomb, Explosive { radius, delay }));
+        },
+        PlacementKind::Trigger { radius, delay } => {
+            entity.insert((
+                Name::new("Trigger"),
+                IsBomb,
+                Explosive { radius, delay },
+                ChainTrigger,
+            ));
+        },
+        PlacementKind::Wall => {
+            entity.insert(Name::new("Wall"));
+        },
+    }
+}
+
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct IsBomb;
+
+impl Configure for IsBomb {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+    }
+}
+
+/// Statistics for the current level.
+#[derive(Resource, Reflect, Default, Debug)]
+#[reflect(Resource)]
+pub struct LevelStats {
+    pub score: u32,
+    pub detonations: u32,
+    pub longest_chain: u32,
+    /// The time spent playing until the outcome was decided.
+    pub time: Duration,
+}
+
+impl Configure for LevelStats {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+        app.add_systems(StateFlush, Screen::Gameplay.on_enter(reset_level_stats));
+        app.add_systems(
+            FixedUpdate,
+            Screen::Gameplay.on_update(
+                (
+                    tick_level_time
+                        .in_set(FixedUpdateSystems::Update)
+                        .run_if(not(resource_exists::<LevelOutcome>)),
+                    record_level_stats
+                        .in_set(FixedUpdateSystems::HandleEvents)
+                        .after(handle_detonations),
+                )
+                    .in_set(PausableSystems),
+            ),
+        );
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn reset_level_stats(mut stats: ResMut<LevelStats>) {
+    *stats = default();
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn tick_level_time(time: Res<Time>, mut stats: ResMut<LevelStats>) {
+    stats.time += time.delta();
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn record_level_stats(mut hop_events: EventReader<ChainHop>, mut stats: ResMut<LevelStats>) {
+    for hop in hop_events.read() {
+        // Each hop scores more the longer the chain reaction has gone on.
+        stats.score += hop.length;
+        stats.detonations += 1;
+        stats.longest_chain = stats.longest_chain.max(hop.length);
+    }
+}
+
+/// A floating "+N" that shows the score from a [`ChainHop`], despawned when its tween finishes.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct ScorePopup;
+
+impl Configure for ScorePopup {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            Update,
+            (spawn_score_popups, despawn_score_popups)
+                .in_set(UpdateSystems::HandleEvents)
+                .in_set(PausableSystems),
+        );
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn spawn_score_popups(mut commands: Commands, mut hop_events: EventReader<ChainHop>) {
+    for hop in hop_events.read() {
+        commands.spawn((
+            Name::new("ScorePopup"),
+            ScorePopup,
+            Text2d::new(format!("+{}", hop.length)),
+            TextFont {
+                font: BOLD_FONT_HANDLE,
+                font_size: 12.0,
+                ..default()
+            },
+            Transform::from_translation(hop.position.extend(2.0)),
+            Tween::new(
+                OffsetLens {
+                    from: Vec2::ZERO,
+                    to: vec2(0.0, 24.0),
+                },
+                0.6,
+            )
+            .with_ease(EaseFunction::QuadraticOut),
+            DespawnOnExitState::<Screen>::default(),
+        ));
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn despawn_score_popups(
+    mut commands: Commands,
+    mut finished_events: EventReader<TweenFinished>,
+    popup_query: Query<(), With<ScorePopup>>,
+) {
+    for event in finished_events.read() {
+        cq!(popup_query.contains(event.entity));
+        commands.entity(event.entity).despawn();
+    }
+}
+
+/// The result of the current level, once decided.
+#[derive(Resource, Reflect, Copy, Clone, Eq, PartialEq, Debug)]
+#[reflect(Resource)]
+pub enum LevelOutcome {
+    Win,
+    Lose,
+}
+
+impl Configure for LevelOutcome {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(StateFlush, Screen::Gameplay.on_enter(remove_level_outcome));
+        app.add_systems(
+            FixedUpdate,
+            Screen::Gameplay.on_update(
+                check_level_outcome
+                    .in_set(FixedUpdateSystems::HandleEvents)
+                    .after(record_level_stats)
+                    .in_set(PausableSystems)
+                    .run_if(not(resource_exists::<Self>)),
+            ),
+        );
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn remove_level_outcome(mut commands: Commands) {
+    commands.remove_resource::<LevelOutcome>();
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn check_level_outcome(
+    mut commands: Commands,
+    config: ConfigRef<LevelConfig>,
+    current_level: Res<CurrentLevel>,
+    stats: Res<LevelStats>,
+    bomb_query: Query<(), With<IsBomb>>,
+    detonator_query: Query<&Detonator>,
+    chain_query: Query<(), With<ChainReaction>>,
+    trigger_query: Query<(), With<ChainTrigger>>,
+) {
+    let config = r!(config.get());
+    let level = r!(config.levels.get(current_level.0));
+
+    let outcome = if level
+        .win_condition
+        .is_met(bomb_query.iter().count(), &stats)
+    {
+        LevelOutcome::Win
+    } else if detonator_query.iter().all(|x| x.charges == 0)
+        && chain_query.is_empty()
+        && trigger_query.is_empty()
+    {
+        LevelOutcome::Lose
+    } else {
+        return;
+    };
+
+    commands.insert_resource(outcome);
+    commands.spawn(transition_to(
+        Screen::Results,
+        Transition::new(TransitionStyle::Iris).with_ease(EaseFunction::CubicInOut),
+    ));
+}
@@ -0,0 +1,17 @@
+//! Game mechanics and content.
+
+pub mod chain_reaction;
+pub mod level;
+pub mod player;
+pub mod progress;
+
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_plugins((
+        chain_reaction::plugin,
+        level::plugin,
+        player::plugin,
+        progress::plugin,
+    ));
+}
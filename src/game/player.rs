@@ -0,0 +1,340 @@
+use crate::animation::facing::Facing;
+use crate::animation::facing::FacingMode;
+use crate::animation::particle::particle_burst;
+use crate::animation::sprite::AnimationState;
+use crate::animation::sprite::SpriteAnimation;
+use crate::animation::sprite::SpriteFrameEvent;
+use crate::animation::sprite::sync_animation_state;
+use crate::animation::sprite::update_sprite_animation;
+use crate::core::audio::SpatialFalloff;
+use crate::core::audio::spatial_sfx;
+use crate::core::camera::PixelSnap;
+use crate::core::input::Binding;
+use crate::core::input::BindingDirection;
+use crate::core::input::Bindings;
+use crate::core::input::InputContext;
+use crate::core::input::Rebindable;
+use crate::game::chain_reaction::Detonate;
+use crate::game::chain_reaction::Explosive;
+use crate::game::chain_reaction::chain_reaction;
+use crate::prelude::*;
+use crate::screen::gameplay::GameplayAssets;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<(
+        PlayerAction,
+        PlayerInputBuffer,
+        PlayerInput,
+        Player,
+        PlayerAnimation,
+        Detonator,
+    )>();
+}
+
+const PLAYER_SIZE: f32 = 16.0;
+
+pub fn player(position: Vec2, charges: u32) -> impl Bundle {
+    (
+        Name::new("Player"),
+        Player { speed: 160.0 },
+        Detonator {
+            charges,
+            radius: 48.0,
+        },
+        Transform::from_translation(position.extend(1.0)),
+        Sprite::from_color(Color::WHITE, Vec2::splat(PLAYER_SIZE)),
+        ThemeColor::Player.set::<Sprite>(),
+        RigidBody::Dynamic,
+        Collider::circle(PLAYER_SIZE / 2.0),
+        LockedAxes::ROTATION_LOCKED,
+        Facing::new(Dir2::X, FacingMode::Mirror),
+        SpriteAnimation::new("player", "idle"),
+        PlayerAnimation::Idle,
+        PixelSnap,
+    )
+}
+
+#[derive(Actionlike, Reflect, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PlayerAction {
+    #[actionlike(DualAxis)]
+    Move,
+    Detonate,
+}
+
+impl Configure for PlayerAction {
+    fn configure(app: &mut App) {
+        app.init_resource::<ActionState<Self>>();
+        app.configure::<Bindings<Self>>();
+        app.add_plugins(InputManagerPlugin::<Self>::default());
+    }
+}
+
+impl Rebindable for PlayerAction {
+    fn default_bindings() -> Vec<Binding<Self>> {
+        vec![
+            Binding::new(Self::Move, KeyCode::KeyW, GamepadButton::DPadUp)
+                .with_direction(BindingDirection::Up),
+            Binding::new(Self::Move, KeyCode::KeyS, GamepadButton::DPadDown)
+                .with_direction(BindingDirection::Down),
+            Binding::new(Self::Move, KeyCode::KeyA, GamepadButton::DPadLeft)
+                .with_direction(BindingDirection::Left),
+            Binding::new(Self::Move, KeyCode::KeyD, GamepadButton::DPadRight)
+                .with_direction(BindingDirection::Right),
+            Binding::new(Self::Detonate, KeyCode::Space, GamepadButton::South),
+        ]
+    }
+
+    fn context(&self) -> InputContext {
+        InputContext::Gameplay
+    }
+
+    fn fixed_input_map() -> InputMap<Self> {
+        InputMap::default()
+            .with_dual_axis(Self::Move, GamepadStick::LEFT)
+            .with_dual_axis(Self::Move, VirtualDPad::arrow_keys())
+    }
+}
+
+/// [`PlayerAction`] input buffered from [`Update`] until the next [`FixedUpdate`] step.
+#[derive(Resource, Reflect, Default, Debug)]
+#[reflect(Resource)]
+struct PlayerInputBuffer {
+    movement: Vec2,
+    detonate: bool,
+}
+
+impl Configure for PlayerInputBuffer {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+        app.add_systems(
+            Update,
+            buffer_player_input
+                .in_set(UpdateSystems::RecordInput)
+                .in_set(PausableSystems),
+        );
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn buffer_player_input(
+    action_state: Res<ActionState<PlayerAction>>,
+    mut buffer: ResMut<PlayerInputBuffer>,
+) {
+    buffer.movement = action_state
+        .axis_pair(&PlayerAction::Move)
+        .clamp_length_max(1.0);
+    // Hold the press until a fixed step consumes it.
+    buffer.detonate |= action_state.just_pressed(&PlayerAction::Detonate);
+}
+
+/// The [`PlayerAction`] input for the current [`FixedUpdate`] step.
+#[derive(Component, Reflect, Default, Copy, Clone, Debug)]
+#[reflect(Component)]
+pub struct PlayerInput {
+    pub movement: Vec2,
+    pub detonate: bool,
+}
+
+impl Configure for PlayerInput {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            FixedUpdate,
+            record_player_input
+                .in_set(FixedUpdateSystems::RecordInput)
+                .in_set(PausableSystems),
+        );
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn record_player_input(
+    mut buffer: ResMut<PlayerInputBuffer>,
+    mut input_query: Query<&mut PlayerInput>,
+) {
+    let input = PlayerInput {
+        movement: buffer.movement,
+        detonate: core::mem::take(&mut buffer.detonate),
+    };
+    for mut player_input in &mut input_query {
+        *player_input = input;
+    }
+}
+
+/// The player-controlled character.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+#[require(PlayerInput)]
+pub struct Player {
+    pub speed: f32,
+}
+
+impl Configure for Player {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            FixedUpdate,
+            apply_player_movement
+                .in_set(FixedUpdateSystems::Update)
+                .in_set(PausableSystems),
+        );
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn apply_player_movement(
+    mut player_query: Query<(
+        &Player,
+        &PlayerInput,
+        &mut LinearVelocity,
+        &mut Facing,
+        &mut PlayerAnimation,
+    )>,
+) {
+    for (player, input, mut velocity, mut facing, mut animation) in &mut player_query {
+        let movement = input.movement;
+        velocity.0 = movement * player.speed;
+        if movement.x != 0.0 {
+            facing.direction = if movement.x < 0.0 {
+                Dir2::NEG_X
+            } else {
+                Dir2::X
+            };
+        }
+
+        // Let the detonate animation finish before walking or idling.
+        cq!(*animation != PlayerAnimation::Detonate);
+        animation.set_if_neq(if movement == Vec2::ZERO {
+            PlayerAnimation::Idle
+        } else {
+            PlayerAnimation::Walk
+        });
+    }
+}
+
+/// The animation state of the [`Player`].
+#[derive(Component, Reflect, Copy, Clone, Eq, PartialEq, Debug)]
+#[reflect(Component)]
+pub enum PlayerAnimation {
+    Idle,
+    Walk,
+    Detonate,
+}
+
+impl Configure for PlayerAnimation {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            Update,
+            (
+                sync_animation_state::<Self>
+                    .in_set(UpdateSystems::SyncLate)
+                    .in_set(PausableSystems)
+                    .before(update_sprite_animation),
+                (play_step_sfx, spawn_detonate_sparks)
+                    .in_set(UpdateSystems::HandleEvents)
+                    .in_set(PausableSystems),
+            ),
+        );
+    }
+}
+
+impl AnimationState for PlayerAnimation {
+    fn clip(self) -> &'static str {
+        match self {
+            Self::Idle => "idle",
+            Self::Walk => "walk",
+            Self::Detonate => "detonate",
+        }
+    }
+
+    fn next(self) -> Option<Self> {
+        match self {
+            Self::Detonate => Some(Self::Idle),
+            _ => None,
+        }
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn play_step_sfx(
+    mut commands: Commands,
+    mut frame_events: EventReader<SpriteFrameEvent>,
+    assets: Res<GameplayAssets>,
+    player_query: Query<&GlobalTransform, With<Player>>,
+) {
+    for event in frame_events.read() {
+        cq!(event.name == "step");
+        let gt = cq!(player_query.get(event.entity));
+        commands
+            .spawn(spatial_sfx(
+                assets.sfx_step.clone(),
+                gt.translation().xy(),
+                SpatialFalloff::default(),
+            ))
+            .entry::<PlaybackSettings>()
+            .and_modify(|mut x| x.speed = 1.5);
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn spawn_detonate_sparks(
+    mut commands: Commands,
+    mut frame_events: EventReader<SpriteFrameEvent>,
+    player_query: Query<&GlobalTransform, With<Player>>,
+) {
+    for event in frame_events.read() {
+        cq!(event.name == "sparks");
+        let gt = cq!(player_query.get(event.entity));
+        commands.spawn(particle_burst("sparks", gt.translation().xy()));
+    }
+}
+
+/// Starts a chain reaction from nearby [`Explosive`]s on [`PlayerAction::Detonate`].
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct Detonator {
+    pub charges: u32,
+    pub radius: f32,
+}
+
+impl Configure for Detonator {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            FixedUpdate,
+            detonate
+                .in_set(FixedUpdateSystems::Update)
+                .in_set(PausableSystems),
+        );
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn detonate(
+    mut commands: Commands,
+    mut detonate_events: EventWriter<Detonate>,
+    mut detonator_query: Query<(
+        &Position,
+        &PlayerInput,
+        &mut Detonator,
+        Option<&mut PlayerAnimation>,
+    )>,
+    explosive_query: Query<(Entity, &Position), With<Explosive>>,
+) {
+    for (position, input, mut detonator, animation) in &mut detonator_query {
+        cq!(input.detonate && detonator.charges > 0);
+        detonator.charges -= 1;
+        if let Some(mut animation) = animation {
+            *animation = PlayerAnimation::Detonate;
+        }
+
+        let center = position.0;
+        let chain = commands.spawn(chain_reaction()).id();
+        for (explosive, explosive_position) in &explosive_query {
+            cq!(explosive_position.distance(center) <= detonator.radius);
+            detonate_events.write(Detonate::root(explosive, chain));
+        }
+    }
+}
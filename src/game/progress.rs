@@ -0,0 +1,173 @@
+use bevy_simple_prefs::Prefs;
+use bevy_simple_prefs::PrefsPlugin;
+
+use crate::game::level::CurrentLevel;
+use crate::game::level::LevelConfig;
+use crate::game::level::LevelOutcome;
+use crate::game::level::LevelStats;
+use crate::prelude::*;
+use crate::screen::Screen;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<(SaveData, SaveVersion, GameProgress)>();
+}
+
+/// Persistent save data, stored separately from the settings.
+#[derive(Prefs, Reflect, Default)]
+struct SaveData {
+    pub save_version: SaveVersion,
+    pub game_progress: GameProgress,
+}
+
+impl Configure for SaveData {
+    fn configure(app: &mut App) {
+        app.add_plugins(PrefsPlugin::<SaveData> {
+            #[cfg(feature = "native")]
+            path: {
+                let path = r!(dirs::config_local_dir()).join(env!("CARGO_PKG_NAME"));
+                // Create parent directories if necessary.
+                r!(std::fs::create_dir_all(&path).is_ok());
+                r!(std::fs::exists(&path));
+                path.join("save.ron")
+            },
+            ..default()
+        });
+    }
+}
+
+/// The schema version of the loaded [`SaveData`].
+///
+/// When the schema changes, bump [`SaveVersion::CURRENT`] and migrate save data from older versions.
+#[derive(Resource, Reflect, Copy, Clone, Eq, PartialEq, Debug)]
+#[reflect(Resource)]
+pub struct SaveVersion(pub u32);
+
+impl Configure for SaveVersion {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+    }
+}
+
+impl Default for SaveVersion {
+    fn default() -> Self {
+        Self::CURRENT
+    }
+}
+
+impl SaveVersion {
+    pub const CURRENT: Self = Self(0);
+}
+
+/// The player's progress through the levels.
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct GameProgress {
+    pub levels: Vec<LevelRecord>,
+    /// The total time spent playing levels.
+    pub play_time: Duration,
+}
+
+impl Configure for GameProgress {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+        app.add_systems(
+            StateFlush,
+            (
+                Screen::Gameplay.on_exit(record_play_time),
+                Screen::Results.on_enter(record_level_outcome),
+            ),
+        );
+    }
+}
+
+impl Default for GameProgress {
+    fn default() -> Self {
+        Self {
+            // The first level is always unlocked.
+            levels: vec![LevelRecord {
+                unlocked: true,
+                ..default()
+            }],
+            play_time: Duration::ZERO,
+        }
+    }
+}
+
+impl GameProgress {
+    pub fn is_completed(&self, level: usize) -> bool {
+        self.levels.get(level).is_some_and(|x| x.completed)
+    }
+
+    pub fn is_unlocked(&self, level: usize) -> bool {
+        self.levels.get(level).is_some_and(|x| x.unlocked)
+    }
+
+    pub fn best_score(&self, level: usize) -> Option<u32> {
+        self.levels.get(level).and_then(|x| x.best_score)
+    }
+
+    pub fn level_mut(&mut self, level: usize) -> &mut LevelRecord {
+        if self.levels.len() <= level {
+            self.levels.resize_with(level + 1, default);
+        }
+        &mut self.levels[level]
+    }
+
+    /// Record a win, unlocking the next level if there is one.
+    pub fn complete_level(&mut self, level: usize, score: u32, level_count: usize) {
+        let record = self.level_mut(level);
+        record.completed = true;
+        record.best_score = record.best_score.max(Some(score));
+        if level + 1 < level_count {
+            self.level_mut(level + 1).unlocked = true;
+        }
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn record_play_time(stats: Res<LevelStats>, mut progress: ResMut<GameProgress>) {
+    progress.play_time += stats.time;
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+pub fn record_level_outcome(
+    outcome: Option<Res<LevelOutcome>>,
+    config: ConfigRef<LevelConfig>,
+    current_level: Res<CurrentLevel>,
+    stats: Res<LevelStats>,
+    mut progress: ResMut<GameProgress>,
+) {
+    rq!(matches!(outcome.as_deref(), Some(LevelOutcome::Win)));
+    let level_count = r!(config.get()).levels.len();
+    progress.complete_level(current_level.0, stats.score, level_count);
+}
+
+/// The player's progress in a single level.
+#[derive(Reflect, Clone, Default, Debug)]
+pub struct LevelRecord {
+    pub unlocked: bool,
+    pub completed: bool,
+    pub best_score: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complete_level() {
+        let mut progress = GameProgress::default();
+
+        progress.complete_level(0, 10, 2);
+        progress.complete_level(0, 5, 2);
+        assert!(progress.is_completed(0));
+        assert_eq!(progress.best_score(0), Some(10));
+        assert!(progress.is_unlocked(1));
+
+        // Completing the last level doesn't create a record past it.
+        progress.complete_level(1, 7, 2);
+        assert_eq!(progress.levels.len(), 2);
+    }
+}
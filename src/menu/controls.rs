@@ -0,0 +1,376 @@
+use core::any::TypeId;
+
+use bevy::ecs::system::SystemParam;
+use leafwing_input_manager::user_input::Buttonlike;
+
+use crate::core::input::Bindings;
+use crate::core::input::InputContext;
+use crate::core::input::Rebindable;
+use crate::core::input::input_map_uses;
+use crate::core::input::key_name;
+use crate::game::player::PlayerAction;
+use crate::menu::Menu;
+use crate::menu::MenuAction;
+use crate::menu::MenuRoot;
+use crate::prelude::*;
+use crate::screen::gameplay::GameplayAction;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(StateFlush, Menu::Controls.on_enter(spawn_controls_menu));
+
+    app.configure::<(
+        RebindButton<PlayerAction>,
+        RebindButton<GameplayAction>,
+        RebindButton<MenuAction>,
+        IsRebinding,
+    )>();
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn spawn_controls_menu(
+    mut commands: Commands,
+    menu_root: Res<MenuRoot>,
+    player_bindings: Res<Bindings<PlayerAction>>,
+    gameplay_bindings: Res<Bindings<GameplayAction>>,
+    menu_bindings: Res<Bindings<MenuAction>>,
+) {
+    let rows = [
+        binding_rows(&player_bindings),
+        binding_rows(&gameplay_bindings),
+        binding_rows(&menu_bindings),
+    ]
+    .concat();
+
+    commands
+        .entity(menu_root.ui)
+        .with_child(widget::body(children![
+            widget::header("[b]Controls"),
+            grid(rows),
+            widget::row_of_buttons(children![
+                widget::wide_button("Reset", reset_bindings),
+                widget::wide_button("Back", go_back),
+            ]),
+        ]));
+}
+
+fn go_back(_: Trigger<Pointer<Click>>, mut menu: ResMut<NextStateStack<Menu>>) {
+    menu.pop();
+}
+
+fn reset_bindings(
+    _: Trigger<Pointer<Click>>,
+    mut player_bindings: ResMut<Bindings<PlayerAction>>,
+    mut gameplay_bindings: ResMut<Bindings<GameplayAction>>,
+    mut menu_bindings: ResMut<Bindings<MenuAction>>,
+) {
+    *player_bindings = default();
+    *gameplay_bindings = default();
+    *menu_bindings = default();
+}
+
+/// A function that spawns the label, key, and gamepad button cells of a binding.
+type BindingRow = Box<dyn FnOnce(&mut ChildSpawner) + Send + Sync>;
+
+fn binding_rows<A: Rebindable>(bindings: &Bindings<A>) -> Vec<BindingRow> {
+    bindings
+        .0
+        .iter()
+        .enumerate()
+        .map(|(i, binding)| {
+            let label = binding.label();
+            Box::new(move |parent: &mut ChildSpawner| {
+                parent.spawn(widget::label(label));
+                parent.spawn((
+                    widget::medium_button("", start_rebinding),
+                    RebindButton::<A>::new(i, BindingDevice::Keyboard),
+                ));
+                parent.spawn((
+                    widget::medium_button("", start_rebinding),
+                    RebindButton::<A>::new(i, BindingDevice::Gamepad),
+                ));
+            }) as BindingRow
+        })
+        .collect()
+}
+
+fn grid(rows: Vec<BindingRow>) -> impl Bundle {
+    (
+        Name::new("Grid"),
+        Node {
+            display: Display::Grid,
+            margin: UiRect::vertical(Vw(2.5)),
+            row_gap: Vw(1.0),
+            column_gap: Vw(1.5),
+            grid_template_columns: vec![
+                RepeatedGridTrack::flex(1, 1.0),
+                RepeatedGridTrack::auto(2),
+                RepeatedGridTrack::flex(1, 1.0),
+                RepeatedGridTrack::auto(2),
+            ],
+            ..default()
+        },
+        GridAlignment::columns([
+            JustifySelf::End,
+            JustifySelf::Center,
+            JustifySelf::Center,
+            JustifySelf::End,
+            JustifySelf::Center,
+            JustifySelf::Center,
+        ]),
+        Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+            for row in rows {
+                row(parent);
+            }
+        })),
+    )
+}
+
+#[derive(Reflect, Copy, Clone, Eq, PartialEq, Debug)]
+enum BindingDevice {
+    Keyboard,
+    Gamepad,
+}
+
+/// A button that displays and rebinds one input of a [`Binding`](crate::core::input::Binding).
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct RebindButton<A: Rebindable> {
+    index: usize,
+    device: BindingDevice,
+    #[reflect(ignore)]
+    _phantom: PhantomData<A>,
+}
+
+impl<A: Rebindable> Configure for RebindButton<A> {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            Update,
+            Menu::Controls.on_update((
+                capture_rebinding::<A>.in_set(UpdateSystems::HandleEvents),
+                update_rebind_buttons::<A>.in_set(UpdateSystems::Update),
+            )),
+        );
+    }
+}
+
+impl<A: Rebindable> RebindButton<A> {
+    fn new(index: usize, device: BindingDevice) -> Self {
+        Self {
+            index,
+            device,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn capture_rebinding<A: Rebindable>(
+    mut commands: Commands,
+    frame: Res<FrameCount>,
+    menu_action: Res<ActionState<MenuAction>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepad_query: Query<&Gamepad>,
+    mut bindings: ParamSet<(ResMut<Bindings<A>>, AllBindings)>,
+    button_query: Query<(Entity, &RebindButton<A>, &IsRebinding)>,
+) {
+    for (entity, button, rebinding) in &button_query {
+        // Ignore the input that started rebinding.
+        cq!(rebinding.0 != frame.0);
+
+        if menu_action.just_pressed(&MenuAction::Back) {
+            commands.entity(entity).remove::<IsRebinding>();
+            continue;
+        }
+        let context = {
+            let bindings = bindings.p0();
+            c!(bindings.0.get(button.index)).action.context()
+        };
+        match button.device {
+            BindingDevice::Keyboard => {
+                let key = *cq!(keyboard.get_just_pressed().next());
+                cq!(!bindings.p1().conflicts::<A>(context, &key));
+                bindings.p0().bind_key(button.index, key);
+            },
+            BindingDevice::Gamepad => {
+                let gamepad_button = *cq!(gamepad_query
+                    .iter()
+                    .flat_map(Gamepad::get_just_pressed)
+                    .next());
+                cq!(!bindings.p1().conflicts::<A>(context, &gamepad_button));
+                bindings.p0().bind_button(button.index, gamepad_button);
+            },
+        }
+        commands.entity(entity).remove::<IsRebinding>();
+    }
+}
+
+/// Every persisted [`Bindings`], for detecting conflicts across action types.
+#[derive(SystemParam)]
+struct AllBindings<'w> {
+    player: Res<'w, Bindings<PlayerAction>>,
+    gameplay: Res<'w, Bindings<GameplayAction>>,
+    menu: Res<'w, Bindings<MenuAction>>,
+}
+
+impl AllBindings<'_> {
+    /// Whether an input is already used by an action in `context`.
+    ///
+    /// Only fixed inputs count for `A` itself, since its own bindings swap instead.
+    fn conflicts<A: Rebindable>(&self, context: InputContext, input: &dyn Buttonlike) -> bool {
+        fn uses<A: Rebindable, B: Rebindable>(
+            bindings: &Bindings<B>,
+            context: InputContext,
+            input: &dyn Buttonlike,
+        ) -> bool {
+            if TypeId::of::<A>() == TypeId::of::<B>() {
+                input_map_uses(&B::fixed_input_map(), context, input)
+            } else {
+                bindings.uses(context, input)
+            }
+        }
+
+        uses::<A, _>(&self.player, context, input)
+            || uses::<A, _>(&self.gameplay, context, input)
+            || uses::<A, _>(&self.menu, context, input)
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn update_rebind_buttons<A: Rebindable>(
+    bindings: Res<Bindings<A>>,
+    button_query: Query<(&RebindButton<A>, Has<IsRebinding>, &Children)>,
+    mut text_query: Query<&mut RichText>,
+) {
+    for (button, is_rebinding, children) in &button_query {
+        let binding = c!(bindings.0.get(button.index));
+        let text = if is_rebinding {
+            "...".to_string()
+        } else {
+            match button.device {
+                BindingDevice::Keyboard => binding.key.map(key_name),
+                BindingDevice::Gamepad => binding.button.map(|x| format!("{x:?}")),
+            }
+            .unwrap_or_else(|| "-".to_string())
+        };
+
+        let label = *c!(children.first());
+        c!(text_query.get_mut(label)).sections = parse_rich(text);
+    }
+}
+
+/// Waiting for the next key or gamepad button press to rebind a [`RebindButton`].
+///
+/// Pressing [`MenuAction::Back`] cancels rebinding. Inputs that conflict with an action in the same
+/// [`InputContext`] are ignored.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct IsRebinding(u32);
+
+impl Configure for IsRebinding {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+    }
+}
+
+fn start_rebinding(
+    trigger: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    frame: Res<FrameCount>,
+    rebinding_query: Query<Entity, With<IsRebinding>>,
+) {
+    let target = r!(trigger.get_target());
+    for entity in &rebinding_query {
+        commands.entity(entity).remove::<IsRebinding>();
+    }
+    commands.entity(target).insert(IsRebinding(frame.0));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rebind<A: Rebindable>(index: usize, key: KeyCode, back: bool) -> App {
+        let mut app = App::new();
+        app.init_resource::<FrameCount>();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<ActionState<MenuAction>>();
+        app.init_resource::<Bindings<PlayerAction>>();
+        app.init_resource::<Bindings<GameplayAction>>();
+        app.init_resource::<Bindings<MenuAction>>();
+        app.add_systems(Update, capture_rebinding::<A>);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(key);
+        if back {
+            app.world_mut()
+                .resource_mut::<ActionState<MenuAction>>()
+                .press(&MenuAction::Back);
+        }
+        app.world_mut().spawn((
+            RebindButton::<A>::new(index, BindingDevice::Keyboard),
+            IsRebinding(1),
+        ));
+        app.update();
+
+        app
+    }
+
+    fn is_rebinding(app: &mut App) -> bool {
+        let world = app.world_mut();
+        world.query::<&IsRebinding>().iter(world).next().is_some()
+    }
+
+    fn detonate_key(app: &App) -> Option<KeyCode> {
+        app.world().resource::<Bindings<PlayerAction>>().0[4].key
+    }
+
+    #[test]
+    fn test_back_cancels_rebinding() {
+        let mut app = rebind::<PlayerAction>(4, KeyCode::KeyF, true);
+        assert!(!is_rebinding(&mut app));
+        assert_eq!(detonate_key(&app), Some(KeyCode::Space));
+    }
+
+    #[test]
+    fn test_rebind_key() {
+        let mut app = rebind::<PlayerAction>(4, KeyCode::KeyF, false);
+        assert!(!is_rebinding(&mut app));
+        assert_eq!(detonate_key(&app), Some(KeyCode::KeyF));
+    }
+
+    #[test]
+    fn test_rebind_escape() {
+        // Escape is only reserved by `MenuAction::Back`, which swaps with `Confirm`.
+        let mut app = rebind::<MenuAction>(0, KeyCode::Escape, false);
+        assert!(!is_rebinding(&mut app));
+        let bindings = app.world().resource::<Bindings<MenuAction>>();
+        assert_eq!(bindings.0[0].key, Some(KeyCode::Escape));
+        assert_eq!(bindings.0[1].key, Some(KeyCode::Enter));
+    }
+
+    #[test]
+    fn test_rebind_conflict_across_maps() {
+        // `GameplayAction::Pause` is bound to P in the same context.
+        let mut app = rebind::<PlayerAction>(4, KeyCode::KeyP, false);
+        assert!(is_rebinding(&mut app));
+        assert_eq!(detonate_key(&app), Some(KeyCode::Space));
+    }
+
+    #[test]
+    fn test_rebind_conflict_with_fixed_input() {
+        // `PlayerAction::Move` is fixed to the arrow keys.
+        let mut app = rebind::<PlayerAction>(4, KeyCode::ArrowUp, false);
+        assert!(is_rebinding(&mut app));
+        assert_eq!(detonate_key(&app), Some(KeyCode::Space));
+    }
+
+    #[test]
+    fn test_rebind_other_context() {
+        // `MenuAction::Confirm` is bound to Enter, but in a different context.
+        let mut app = rebind::<PlayerAction>(4, KeyCode::Enter, false);
+        assert!(!is_rebinding(&mut app));
+        assert_eq!(detonate_key(&app), Some(KeyCode::Enter));
+    }
+}
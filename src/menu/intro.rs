@@ -2,7 +2,8 @@ use crate::menu::Menu;
 use crate::menu::MenuRoot;
 use crate::prelude::*;
 use crate::screen::Screen;
-use crate::screen::fade::fade_out;
+use crate::screen::transition::TransitionStyle;
+use crate::screen::transition::transition_to;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(StateFlush, Menu::Intro.on_enter(spawn_intro_menu));
@@ -32,9 +33,10 @@ fn start_game(
     progress: Res<ProgressTracker<BevyState<Screen>>>,
 ) {
     let Progress { done, total } = progress.get_global_combined_progress();
-    commands.spawn(fade_out(if done >= total {
+    let to_screen = if done >= total {
         Screen::Gameplay
     } else {
         Screen::Loading
-    }));
+    };
+    commands.spawn(transition_to(to_screen, TransitionStyle::Fade));
 }
@@ -0,0 +1,113 @@
+use crate::game::level::CurrentLevel;
+use crate::game::level::LevelConfig;
+use crate::game::progress::GameProgress;
+use crate::menu::Menu;
+use crate::menu::MenuRoot;
+use crate::prelude::*;
+use crate::screen::Screen;
+use crate::screen::transition::TransitionStyle;
+use crate::screen::transition::transition_to;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        StateFlush,
+        Menu::LevelSelect.on_enter(spawn_level_select_menu),
+    );
+
+    app.configure::<LevelSelectButton>();
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn spawn_level_select_menu(
+    mut commands: Commands,
+    menu_root: Res<MenuRoot>,
+    config: ConfigRef<LevelConfig>,
+    progress: Res<GameProgress>,
+) {
+    let config = r!(config.get());
+    let levels = config
+        .levels
+        .iter()
+        .enumerate()
+        .map(|(i, level)| {
+            (
+                i,
+                level.name.clone(),
+                progress.is_unlocked(i),
+                progress.is_completed(i),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    commands
+        .entity(menu_root.ui)
+        .with_child(widget::body(children![
+            widget::header("[b]Select level"),
+            grid(levels),
+            widget::row_of_buttons(children![widget::wide_button("Back", go_back)]),
+        ]));
+}
+
+fn go_back(_: Trigger<Pointer<Click>>, mut menu: ResMut<NextStateStack<Menu>>) {
+    menu.pop();
+}
+
+fn grid(levels: Vec<(usize, String, bool, bool)>) -> impl Bundle {
+    (
+        Name::new("Grid"),
+        Node {
+            display: Display::Grid,
+            margin: UiRect::vertical(Vw(5.0)),
+            row_gap: Vw(1.4),
+            column_gap: Vw(6.0),
+            grid_template_columns: vec![
+                RepeatedGridTrack::flex(1, 1.0),
+                RepeatedGridTrack::flex(1, 1.0),
+            ],
+            ..default()
+        },
+        GridAlignment::columns([JustifySelf::End, JustifySelf::Start]),
+        Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+            for (i, name, unlocked, completed) in levels {
+                parent.spawn((
+                    widget::button(name, start_level),
+                    LevelSelectButton(i),
+                    InteractionDisabled(!unlocked),
+                ));
+                parent.spawn(widget::label(if completed {
+                    "Complete"
+                } else if unlocked {
+                    ""
+                } else {
+                    "Locked"
+                }));
+            }
+        })),
+    )
+}
+
+/// A button that starts the level at the given index in [`LevelConfig::levels`].
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+struct LevelSelectButton(usize);
+
+impl Configure for LevelSelectButton {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+    }
+}
+
+fn start_level(
+    trigger: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    button_query: Query<&LevelSelectButton>,
+    progress: Res<GameProgress>,
+    mut current_level: ResMut<CurrentLevel>,
+) {
+    let target = r!(trigger.get_target());
+    let level = r!(button_query.get(target)).0;
+    rq!(progress.is_unlocked(level));
+
+    current_level.0 = level;
+    commands.spawn(transition_to(Screen::Loading, TransitionStyle::Slide));
+}
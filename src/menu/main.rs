@@ -1,3 +1,7 @@
+use crate::animation::tween::RotationLens;
+use crate::animation::tween::ScaleLens;
+use crate::animation::tween::Tween;
+use crate::animation::tween::TweenMode;
 use crate::menu::Menu;
 use crate::menu::MenuRoot;
 use crate::prelude::*;
@@ -11,9 +15,30 @@ fn spawn_main_menu(mut commands: Commands, menu_root: Res<MenuRoot>) {
     commands
         .entity(menu_root.ui)
         .with_child(widget::body(children![
-            widget::header("[b]Bevy Jam 6"),
+            (
+                widget::header("[b]Bevy Jam 6"),
+                Tween::new(
+                    ScaleLens {
+                        from: Vec2::ONE,
+                        to: Vec2::splat(1.05),
+                    },
+                    1.2,
+                )
+                .with_ease(EaseFunction::SineInOut)
+                .with_mode(TweenMode::PingPong),
+                Tween::new(
+                    RotationLens {
+                        from: -0.03,
+                        to: 0.03,
+                    },
+                    2.0,
+                )
+                .with_ease(EaseFunction::SineInOut)
+                .with_mode(TweenMode::PingPong),
+            ),
             widget::column_of_buttons(children![
                 widget::big_button("Play", open_intro),
+                widget::big_button("Levels", open_level_select),
                 widget::big_button("Settings", open_settings),
                 (
                     widget::big_button("Quit", quit_to_desktop),
@@ -28,6 +53,10 @@ fn open_intro(_: Trigger<Pointer<Click>>, mut menu: ResMut<NextStateStack<Menu>>
     menu.push(Menu::Intro);
 }
 
+fn open_level_select(_: Trigger<Pointer<Click>>, mut menu: ResMut<NextStateStack<Menu>>) {
+    menu.push(Menu::LevelSelect);
+}
+
 fn open_settings(_: Trigger<Pointer<Click>>, mut menu: ResMut<NextStateStack<Menu>>) {
     menu.push(Menu::Settings);
 }
@@ -1,9 +1,17 @@
+mod controls;
 mod intro;
+mod level_select;
 mod main;
 mod pause;
 mod settings;
 
+use crate::core::input::Binding;
+use crate::core::input::Bindings;
+use crate::core::input::InputContext;
+use crate::core::input::Rebindable;
+use crate::menu::controls::IsRebinding;
 use crate::prelude::*;
+use crate::theme::focus;
 
 pub(super) fn plugin(app: &mut App) {
     app.configure::<(MenuRoot, Menu, MenuAction)>();
@@ -44,6 +52,8 @@ impl FromWorld for MenuRoot {
 pub enum Menu {
     Main,
     Intro,
+    Controls,
+    LevelSelect,
     Pause,
     Settings,
 }
@@ -59,29 +69,161 @@ impl Configure for Menu {
                 Menu::ANY.on_disable(Pause::disable),
             ),
         );
-        app.add_plugins((main::plugin, intro::plugin, pause::plugin, settings::plugin));
+        app.add_plugins((
+            main::plugin,
+            controls::plugin,
+            intro::plugin,
+            level_select::plugin,
+            pause::plugin,
+            settings::plugin,
+        ));
     }
 }
 
 #[derive(Actionlike, Reflect, Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum MenuAction {
     Back,
+    Up,
+    Down,
+    Left,
+    Right,
+    Confirm,
 }
 
 impl Configure for MenuAction {
     fn configure(app: &mut App) {
         app.init_resource::<ActionState<Self>>();
-        app.insert_resource(
-            InputMap::default()
-                .with(Self::Back, GamepadButton::South)
-                .with(Self::Back, KeyCode::Escape),
-        );
+        app.configure::<Bindings<Self>>();
         app.add_plugins(InputManagerPlugin::<Self>::default());
+        app.add_systems(StateFlush, Menu::ANY.on_exit(Focus::clear));
         app.add_systems(
             Update,
-            Menu::pop
-                .in_set(UpdateSystems::RecordInput)
-                .run_if(Menu::is_enabled.and(action_just_pressed(Self::Back))),
+            (
+                Menu::pop
+                    .in_set(UpdateSystems::RecordInput)
+                    .run_if(Menu::is_enabled.and(action_just_pressed(Self::Back))),
+                navigate_focus.in_set(UpdateSystems::RecordInput),
+            )
+                .run_if(not(any_with_component::<IsRebinding>)),
         );
     }
 }
+
+impl Rebindable for MenuAction {
+    fn default_bindings() -> Vec<Binding<Self>> {
+        vec![
+            Binding::new(Self::Confirm, KeyCode::Enter, GamepadButton::South),
+            Binding::new(Self::Back, KeyCode::Escape, GamepadButton::East),
+        ]
+    }
+
+    fn context(&self) -> InputContext {
+        InputContext::Menu
+    }
+
+    fn fixed_input_map() -> InputMap<Self> {
+        InputMap::default()
+            .with(Self::Up, GamepadButton::DPadUp)
+            .with(Self::Up, GamepadControlDirection::LEFT_UP)
+            .with(Self::Up, KeyCode::ArrowUp)
+            .with(Self::Up, KeyCode::KeyW)
+            .with(Self::Down, GamepadButton::DPadDown)
+            .with(Self::Down, GamepadControlDirection::LEFT_DOWN)
+            .with(Self::Down, KeyCode::ArrowDown)
+            .with(Self::Down, KeyCode::KeyS)
+            .with(Self::Left, GamepadButton::DPadLeft)
+            .with(Self::Left, GamepadControlDirection::LEFT_LEFT)
+            .with(Self::Left, KeyCode::ArrowLeft)
+            .with(Self::Left, KeyCode::KeyA)
+            .with(Self::Right, GamepadButton::DPadRight)
+            .with(Self::Right, GamepadControlDirection::LEFT_RIGHT)
+            .with(Self::Right, KeyCode::ArrowRight)
+            .with(Self::Right, KeyCode::KeyD)
+            .with(Self::Confirm, KeyCode::Space)
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn navigate_focus(
+    mut commands: Commands,
+    action_state: Res<ActionState<MenuAction>>,
+    menu: CurrentRef<Menu>,
+    menu_root: Res<MenuRoot>,
+    mut focus: ResMut<Focus>,
+    focusable_query: Query<(
+        Entity,
+        &Focusable,
+        &GlobalTransform,
+        &InheritedVisibility,
+        Option<&InteractionDisabled>,
+    )>,
+    parent_query: Query<&ChildOf>,
+    children_query: Query<&Children>,
+) {
+    let direction = [
+        (MenuAction::Up, Vec2::NEG_Y),
+        (MenuAction::Down, Vec2::Y),
+        (MenuAction::Left, Vec2::NEG_X),
+        (MenuAction::Right, Vec2::X),
+    ]
+    .into_iter()
+    .find(|(action, _)| action_state.just_pressed(action))
+    .map(|(_, direction)| direction);
+    let confirm = action_state.just_pressed(&MenuAction::Confirm);
+    rq!(direction.is_some() || confirm);
+
+    // Only consider the menu while it's open, or else the screen behind it.
+    let candidates = focusable_query
+        .iter()
+        .filter(|(entity, _, _, visibility, disabled)| {
+            visibility.get()
+                && !matches!(disabled, Some(InteractionDisabled(true)))
+                && (!menu.is_enabled()
+                    || parent_query
+                        .iter_ancestors(*entity)
+                        .any(|x| x == menu_root.ui))
+        })
+        .map(|(entity, &focusable, gt, ..)| (entity, focusable, gt.translation().xy()))
+        .collect::<Vec<_>>();
+
+    // Focus the top-left candidate if nothing is focused yet.
+    let Some(&(current, focusable, position)) =
+        focus.0.and_then(|x| candidates.iter().find(|y| y.0 == x))
+    else {
+        focus.0 = candidates
+            .iter()
+            .min_by(|a, b| a.2.y.total_cmp(&b.2.y).then(a.2.x.total_cmp(&b.2.x)))
+            .map(|x| x.0);
+        return;
+    };
+
+    if confirm {
+        rq!(focusable == Focusable::Button);
+        commands.queue(move |world: &mut World| focus::click(world, current));
+        return;
+    }
+    let direction = r!(direction);
+
+    // Selectors handle horizontal navigation themselves.
+    if focusable == Focusable::Selector && direction.y == 0.0 {
+        let children = r!(children_query.get(current));
+        let button = *r!(if direction.x < 0.0 {
+            children.first()
+        } else {
+            children.last()
+        });
+        commands.queue(move |world: &mut World| focus::click(world, button));
+        return;
+    }
+
+    // Move to the nearest candidate in the given direction, preferring aligned candidates.
+    let next = candidates
+        .iter()
+        .filter_map(|&(entity, _, other)| {
+            let delta = other - position;
+            let distance = delta.dot(direction);
+            (distance > 1.0).then(|| (entity, distance + 2.0 * delta.perp_dot(direction).abs()))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1));
+    focus.0 = Some(rq!(next).0);
+}
@@ -2,7 +2,8 @@ use crate::menu::Menu;
 use crate::menu::MenuRoot;
 use crate::prelude::*;
 use crate::screen::Screen;
-use crate::screen::fade::fade_out;
+use crate::screen::transition::TransitionStyle;
+use crate::screen::transition::transition_to;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(StateFlush, Menu::Pause.on_enter(spawn_pause_menu));
@@ -32,9 +33,9 @@ fn close_menu(_: Trigger<Pointer<Click>>, mut menu: NextMut<Menu>) {
 }
 
 fn restart_game(_: Trigger<Pointer<Click>>, mut commands: Commands) {
-    commands.spawn(fade_out(Screen::Gameplay));
+    commands.spawn(transition_to(Screen::Gameplay, TransitionStyle::Wipe));
 }
 
 fn quit_to_title(_: Trigger<Pointer<Click>>, mut commands: Commands) {
-    commands.spawn(fade_out(Screen::Title));
+    commands.spawn(transition_to(Screen::Title, TransitionStyle::Fade));
 }
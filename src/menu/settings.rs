@@ -1,20 +1,22 @@
 use bevy_simple_prefs::Prefs;
 use bevy_simple_prefs::PrefsPlugin;
 
+use crate::core::audio::AudioBus;
 use crate::core::audio::AudioSettings;
+use crate::core::input::Bindings;
+use crate::core::window::DisplaySettings;
+use crate::game::player::PlayerAction;
 use crate::menu::Menu;
+use crate::menu::MenuAction;
 use crate::menu::MenuRoot;
 use crate::prelude::*;
+use crate::screen::gameplay::GameplayAction;
+use crate::theme::setting::format_percent;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(StateFlush, Menu::Settings.on_enter(spawn_settings_menu));
 
-    app.configure::<(
-        Settings,
-        IsMasterVolumeSelector,
-        IsMusicVolumeSelector,
-        IsUiVolumeSelector,
-    )>();
+    app.configure::<Settings>();
 }
 
 #[cfg_attr(feature = "native_dev", hot)]
@@ -24,10 +26,17 @@ fn spawn_settings_menu(mut commands: Commands, menu_root: Res<MenuRoot>) {
         .with_child(widget::body(children![
             widget::header("[b]Settings"),
             grid(),
-            widget::row_of_buttons(children![widget::wide_button("Back", go_back)]),
+            widget::row_of_buttons(children![
+                widget::wide_button("Controls", open_controls),
+                widget::wide_button("Back", go_back),
+            ]),
         ]));
 }
 
+fn open_controls(_: Trigger<Pointer<Click>>, mut menu: ResMut<NextStateStack<Menu>>) {
+    menu.push(Menu::Controls);
+}
+
 fn go_back(_: Trigger<Pointer<Click>>, mut menu: ResMut<NextStateStack<Menu>>) {
     menu.pop();
 }
@@ -47,167 +56,54 @@ fn grid() -> impl Bundle {
             ..default()
         },
         GridAlignment::columns([JustifySelf::End, JustifySelf::Start]),
-        children![
-            widget::label("Master volume"),
-            widget::selector(IsMasterVolumeSelector, master_volume_down, master_volume_up),
-            widget::label("Music volume"),
-            widget::selector(IsMusicVolumeSelector, music_volume_down, music_volume_up),
-            widget::label("UI volume"),
-            widget::selector(IsUiVolumeSelector, ui_volume_down, ui_volume_up),
-        ],
+        Children::spawn(SpawnWith(|parent: &mut ChildSpawner| {
+            parent.spawn(widget::label("Master volume"));
+            parent.spawn(widget::setting_selector(volume("master_volume")));
+            for bus in AudioBus::ALL {
+                parent.spawn(widget::label(format!("{} volume", bus.name())));
+                parent.spawn(widget::setting_selector(volume(bus.volume_path())));
+            }
+
+            let window_mode = SettingSelector::cycle::<DisplaySettings>("window_mode");
+            let vsync = SettingSelector::toggle::<DisplaySettings>("vsync");
+            let ui_scale = SettingSelector::float::<DisplaySettings>(
+                "ui_scale",
+                0.25,
+                0.5..=2.0,
+                format_percent,
+            );
+            let screen_shake = SettingSelector::float::<DisplaySettings>(
+                "screen_shake",
+                0.25,
+                0.0..=1.0,
+                format_percent,
+            );
+            let pixel_perfect = SettingSelector::toggle::<DisplaySettings>("pixel_perfect");
+            parent.spawn(widget::label("Window mode"));
+            parent.spawn(widget::setting_selector(window_mode));
+            parent.spawn(widget::label("VSync"));
+            parent.spawn(widget::setting_selector(vsync));
+            parent.spawn(widget::label("UI scale"));
+            parent.spawn(widget::setting_selector(ui_scale));
+            parent.spawn(widget::label("Pixel perfect"));
+            parent.spawn(widget::setting_selector(pixel_perfect));
+            parent.spawn(widget::label("Screen shake"));
+            parent.spawn(widget::setting_selector(screen_shake));
+        })),
     )
 }
 
-#[derive(Component, Reflect, Debug)]
-#[reflect(Component)]
-struct IsMasterVolumeSelector;
-
-impl Configure for IsMasterVolumeSelector {
-    fn configure(app: &mut App) {
-        app.register_type::<Self>();
-        app.add_systems(
-            Update,
-            Menu::Settings.on_update(update_master_volume_selector.in_set(UpdateSystems::Update)),
-        );
-    }
-}
-
-#[cfg_attr(feature = "native_dev", hot)]
-fn update_master_volume_selector(
-    audio_settings: Res<AudioSettings>,
-    selector_query: Query<Entity, With<IsMasterVolumeSelector>>,
-    children_query: Query<&Children>,
-    mut text_query: Query<&mut RichText>,
-    mut disabled_query: Query<&mut InteractionDisabled>,
-) {
-    for entity in &selector_query {
-        let children = c!(children_query.get(entity))
-            .into_iter()
-            .collect::<Vec<_>>();
-
-        let left = **c!(children.first());
-        c!(disabled_query.get_mut(left)).0 = audio_settings.master_volume <= f32::EPSILON;
-
-        let mid = **c!(children.get(1));
-        let mid_children = c!(children_query.get(mid));
-        let label = *c!(mid_children.first());
-        c!(text_query.get_mut(label)).sections =
-            parse_rich(format!("{:.0}%", audio_settings.master_volume * 100.0));
-
-        let right = **c!(children.get(2));
-        c!(disabled_query.get_mut(right)).0 = audio_settings.master_volume >= 1.0 - f32::EPSILON;
-    }
-}
-
-fn master_volume_down(_: Trigger<Pointer<Click>>, mut audio_settings: ResMut<AudioSettings>) {
-    audio_settings.master_volume = (audio_settings.master_volume - 0.1).max(0.0);
-}
-
-fn master_volume_up(_: Trigger<Pointer<Click>>, mut audio_settings: ResMut<AudioSettings>) {
-    audio_settings.master_volume = (audio_settings.master_volume + 0.1).min(1.0);
-}
-
-#[derive(Component, Reflect, Debug)]
-#[reflect(Component)]
-struct IsMusicVolumeSelector;
-
-impl Configure for IsMusicVolumeSelector {
-    fn configure(app: &mut App) {
-        app.register_type::<Self>();
-        app.add_systems(
-            Update,
-            Menu::Settings.on_update(update_music_volume_selector.in_set(UpdateSystems::Update)),
-        );
-    }
-}
-
-#[cfg_attr(feature = "native_dev", hot)]
-fn update_music_volume_selector(
-    audio_settings: Res<AudioSettings>,
-    selector_query: Query<Entity, With<IsMusicVolumeSelector>>,
-    children_query: Query<&Children>,
-    mut text_query: Query<&mut RichText>,
-    mut disabled_query: Query<&mut InteractionDisabled>,
-) {
-    for entity in &selector_query {
-        let children = c!(children_query.get(entity))
-            .into_iter()
-            .collect::<Vec<_>>();
-
-        let left = **c!(children.first());
-        c!(disabled_query.get_mut(left)).0 = audio_settings.music_volume <= f32::EPSILON;
-
-        let mid = **c!(children.get(1));
-        let mid_children = c!(children_query.get(mid));
-        let label = *c!(mid_children.first());
-        c!(text_query.get_mut(label)).sections =
-            parse_rich(format!("{:.0}%", audio_settings.music_volume * 100.0));
-
-        let right = **c!(children.get(2));
-        c!(disabled_query.get_mut(right)).0 = audio_settings.music_volume >= 1.0 - f32::EPSILON;
-    }
-}
-
-fn music_volume_down(_: Trigger<Pointer<Click>>, mut audio_settings: ResMut<AudioSettings>) {
-    audio_settings.music_volume = (audio_settings.music_volume - 0.1).max(0.0);
-}
-
-fn music_volume_up(_: Trigger<Pointer<Click>>, mut audio_settings: ResMut<AudioSettings>) {
-    audio_settings.music_volume = (audio_settings.music_volume + 0.1).min(1.0);
-}
-
-#[derive(Component, Reflect, Debug)]
-#[reflect(Component)]
-struct IsUiVolumeSelector;
-
-impl Configure for IsUiVolumeSelector {
-    fn configure(app: &mut App) {
-        app.register_type::<Self>();
-        app.add_systems(
-            Update,
-            Menu::Settings.on_update(update_ui_volume_selector.in_set(UpdateSystems::Update)),
-        );
-    }
-}
-
-#[cfg_attr(feature = "native_dev", hot)]
-fn update_ui_volume_selector(
-    audio_settings: Res<AudioSettings>,
-    selector_query: Query<Entity, With<IsUiVolumeSelector>>,
-    children_query: Query<&Children>,
-    mut text_query: Query<&mut RichText>,
-    mut disabled_query: Query<&mut InteractionDisabled>,
-) {
-    for entity in &selector_query {
-        let children = c!(children_query.get(entity))
-            .into_iter()
-            .collect::<Vec<_>>();
-
-        let left = **c!(children.first());
-        c!(disabled_query.get_mut(left)).0 = audio_settings.ui_volume <= f32::EPSILON;
-
-        let mid = **c!(children.get(1));
-        let mid_children = c!(children_query.get(mid));
-        let label = *c!(mid_children.first());
-        c!(text_query.get_mut(label)).sections =
-            parse_rich(format!("{:.0}%", audio_settings.ui_volume * 100.0));
-
-        let right = **c!(children.get(2));
-        c!(disabled_query.get_mut(right)).0 = audio_settings.ui_volume >= 1.0 - f32::EPSILON;
-    }
-}
-
-fn ui_volume_down(_: Trigger<Pointer<Click>>, mut audio_settings: ResMut<AudioSettings>) {
-    audio_settings.ui_volume = (audio_settings.ui_volume - 0.1).max(0.0);
-}
-
-fn ui_volume_up(_: Trigger<Pointer<Click>>, mut audio_settings: ResMut<AudioSettings>) {
-    audio_settings.ui_volume = (audio_settings.ui_volume + 0.1).min(1.0);
+fn volume(path: &'static str) -> SettingSelector {
+    SettingSelector::float::<AudioSettings>(path, 0.1, 0.0..=1.0, format_percent)
 }
 
 #[derive(Prefs, Reflect, Default)]
 struct Settings {
     pub audio_settings: AudioSettings,
+    pub display_settings: DisplaySettings,
+    pub player_bindings: Bindings<PlayerAction>,
+    pub gameplay_bindings: Bindings<GameplayAction>,
+    pub menu_bindings: Bindings<MenuAction>,
 }
 
 impl Configure for Settings {
@@ -1,38 +1,80 @@
-use crate::core::audio::AudioSettings;
-use crate::core::audio::music_audio;
+use crate::animation::tween::AlphaLens;
+use crate::animation::tween::Tween;
+use crate::core::camera::CameraRoot;
+use crate::core::camera::CameraZoom;
+use crate::core::camera::SmoothFollow;
+use crate::core::input::Binding;
+use crate::core::input::Bindings;
+use crate::core::input::InputContext;
+use crate::core::input::Rebindable;
+use crate::game::level::CurrentLevel;
+use crate::game::level::LevelConfig;
+use crate::game::level::level;
+use crate::game::player::Player;
 use crate::menu::Menu;
 use crate::prelude::*;
 use crate::screen::Screen;
-use crate::screen::ScreenRoot;
+use crate::screen::music::MusicManager;
+use crate::screen::music::play_screen_playlist;
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_systems(StateFlush, Screen::Gameplay.on_enter(spawn_gameplay_screen));
+    app.add_systems(
+        StateFlush,
+        Screen::Gameplay.on_enter(spawn_gameplay_screen.after(play_screen_playlist)),
+    );
+
+    app.add_observer(follow_player);
 
     app.configure::<(GameplayAssets, GameplayAction)>();
 }
 
 fn spawn_gameplay_screen(
     mut commands: Commands,
-    screen_root: Res<ScreenRoot>,
-    audio_settings: Res<AudioSettings>,
-    assets: Res<GameplayAssets>,
+    asset_server: Res<AssetServer>,
+    mut music_manager: ResMut<MusicManager>,
+    camera_root: Res<CameraRoot>,
+    mut camera_query: Query<(&mut SmoothFollow, &mut CameraZoom)>,
+    config: ConfigRef<LevelConfig>,
+    current_level: Res<CurrentLevel>,
 ) {
-    commands
-        .entity(screen_root.ui)
-        .with_child(widget::column_center(children![widget::label(
-            "Gameplay goes here. Press P to pause!",
-        )]));
+    let config = r!(config.get());
+    let level_data = r!(config.levels.get(current_level.0));
     commands.spawn((
-        music_audio(&audio_settings, assets.music.clone()),
+        level(level_data, &asset_server),
         DespawnOnExitState::<Screen>::Recursive,
     ));
+
+    let (mut follow, mut zoom) = r!(camera_query.get_mut(camera_root.primary));
+    follow.bounds = level_data.camera_bounds;
+    if let Some(bounds) = level_data.camera_bounds {
+        zoom.zoom_to_fit(bounds);
+    }
+
+    // Override the gameplay playlist with the level's own music.
+    if let Some(path) = &level_data.music {
+        music_manager.play(asset_server.load(path));
+    }
+}
+
+/// Point the camera at the player when it spawns.
+fn follow_player(
+    trigger: Trigger<OnAdd, Player>,
+    camera_root: Res<CameraRoot>,
+    mut follow_query: Query<&mut SmoothFollow>,
+) {
+    let target = r!(trigger.get_target());
+    r!(follow_query.get_mut(camera_root.primary)).follow(target);
 }
 
 #[derive(AssetCollection, Resource, Reflect, Default)]
 #[reflect(Resource)]
 pub struct GameplayAssets {
-    #[asset(path = "audio/music/545458__bertsz__bit-forest-evil-theme-music.ogg")]
-    music: Handle<AudioSource>,
+    // TODO: Replace this placeholder with a dedicated explosion sample.
+    #[asset(path = "audio/sfx/253168__suntemple__sfx-ui-button-click.ogg")]
+    pub sfx_explosion: Handle<AudioSource>,
+    // TODO: Replace this placeholder with a dedicated footstep sample.
+    #[asset(path = "audio/sfx/251390__deadsillyrabbit__button_hover-mp3.ogg")]
+    pub sfx_step: Handle<AudioSource>,
 }
 
 impl Configure for GameplayAssets {
@@ -51,13 +93,7 @@ pub enum GameplayAction {
 impl Configure for GameplayAction {
     fn configure(app: &mut App) {
         app.init_resource::<ActionState<Self>>();
-        app.insert_resource(
-            InputMap::default()
-                .with(Self::Pause, GamepadButton::Start)
-                .with(Self::Pause, KeyCode::Escape)
-                .with(Self::Pause, KeyCode::KeyP)
-                .with(Self::CloseMenu, KeyCode::KeyP),
-        );
+        app.configure::<Bindings<Self>>();
         app.add_plugins(InputManagerPlugin::<Self>::default());
         app.add_systems(
             Update,
@@ -73,10 +109,34 @@ impl Configure for GameplayAction {
     }
 }
 
+impl Rebindable for GameplayAction {
+    fn default_bindings() -> Vec<Binding<Self>> {
+        vec![Binding::new(
+            Self::Pause,
+            KeyCode::KeyP,
+            GamepadButton::Start,
+        )]
+    }
+
+    fn context(&self) -> InputContext {
+        match self {
+            Self::Pause => InputContext::Gameplay,
+            Self::CloseMenu => InputContext::Menu,
+        }
+    }
+
+    fn fixed_input_map() -> InputMap<Self> {
+        InputMap::default()
+            .with(Self::Pause, KeyCode::Escape)
+            .with(Self::CloseMenu, KeyCode::KeyP)
+    }
+}
+
 fn spawn_pause_overlay(mut commands: Commands) {
     commands.spawn((
         widget::blocking_overlay(1),
         ThemeColor::Overlay.set::<BackgroundColor>(),
+        Tween::new(AlphaLens::<BackgroundColor>::new(0.0, 1.0), 0.15),
         DespawnOnExitState::<Screen>::default(),
         DespawnOnDisableState::<Menu>::default(),
     ));
@@ -1,8 +1,10 @@
 use crate::prelude::*;
 use crate::screen::Screen;
 use crate::screen::ScreenRoot;
-use crate::screen::fade::fade_out;
 use crate::screen::gameplay::GameplayAssets;
+use crate::screen::transition::Transition;
+use crate::screen::transition::TransitionStyle;
+use crate::screen::transition::transition_to;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_loading_state(
@@ -37,7 +39,10 @@ fn update_loading(
 
     // Continue to the next screen when ready.
     if done == total {
-        commands.spawn(fade_out(Screen::Gameplay));
+        commands.spawn(transition_to(
+            Screen::Gameplay,
+            Transition::new(TransitionStyle::Dissolve).with_durations(0.4, 0.6),
+        ));
     }
 
     info!("[Frame {}] Loading: {done} / {total}", frame.0);
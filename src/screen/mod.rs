@@ -1,13 +1,19 @@
-pub mod fade;
-mod gameplay;
+pub mod gameplay;
 mod loading;
+pub mod music;
+mod results;
 mod splash;
 mod title;
+pub mod transition;
 
 use bevy::ecs::schedule::ScheduleConfigs;
 use bevy::ecs::system::ScheduleSystem;
 
 use crate::core::camera::CameraRoot;
+use crate::core::camera::CameraTrauma;
+use crate::core::camera::CameraZoom;
+use crate::core::camera::SmoothFollow;
+use crate::core::time::TimeDilation;
 use crate::core::window::WindowReady;
 use crate::menu::Menu;
 use crate::prelude::*;
@@ -16,7 +22,7 @@ use crate::theme::widget::IsLoadingBarFill;
 pub fn plugin(app: &mut App) {
     app.configure::<(ScreenRoot, Screen, ScreenTime)>();
 
-    app.add_plugins(fade::plugin);
+    app.add_plugins((music::plugin, transition::plugin));
 }
 
 #[derive(Resource, Reflect)]
@@ -57,6 +63,7 @@ pub enum Screen {
     Title,
     Loading,
     Gameplay,
+    Results,
 }
 
 impl Configure for Screen {
@@ -72,6 +79,7 @@ impl Configure for Screen {
                     Pause::disable,
                     (Menu::release, Menu::clear).chain(),
                     reset_screen_camera,
+                    reset_time_dilation,
                 )),
             ),
         );
@@ -80,13 +88,30 @@ impl Configure for Screen {
             title::plugin,
             loading::plugin,
             gameplay::plugin,
+            results::plugin,
         ));
     }
 }
 
-fn reset_screen_camera(camera_root: Res<CameraRoot>, mut camera_query: Query<&mut Transform>) {
-    let mut transform = r!(camera_query.get_mut(camera_root.primary));
+fn reset_screen_camera(
+    camera_root: Res<CameraRoot>,
+    mut camera_query: Query<(
+        &mut Transform,
+        &mut CameraTrauma,
+        &mut SmoothFollow,
+        &mut CameraZoom,
+    )>,
+) {
+    let (mut transform, mut trauma, mut follow, mut zoom) =
+        r!(camera_query.get_mut(camera_root.primary));
     *transform = default();
+    trauma.trauma = 0.0;
+    follow.reset();
+    zoom.reset();
+}
+
+fn reset_time_dilation(mut dilation: ResMut<TimeDilation>) {
+    dilation.clear();
 }
 
 /// The total time elapsed in the current screen.
@@ -109,7 +134,7 @@ fn reset_screen_time(mut screen_time: ResMut<ScreenTime>) {
 }
 
 #[cfg_attr(feature = "native_dev", hot)]
-fn tick_screen_time(time: Res<Time>, mut screen_time: ResMut<ScreenTime>) {
+fn tick_screen_time(time: Res<Time<Real>>, mut screen_time: ResMut<ScreenTime>) {
     screen_time.0 += time.delta();
 }
 
@@ -0,0 +1,230 @@
+use crate::core::audio::AudioGain;
+use crate::core::audio::music_audio;
+use crate::prelude::*;
+use crate::screen::Screen;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<(ConfigHandle<MusicConfig>, MusicManager, MusicFade)>();
+}
+
+#[derive(Asset, Reflect, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MusicConfig {
+    /// The duration of a crossfade between tracks, in seconds.
+    pub fade_duration: f32,
+    /// The volume multiplier for music while the game is paused.
+    pub pause_volume: f32,
+    /// The tracks to play in each screen, in order.
+    ///
+    /// A track shared by consecutive screens keeps playing across the screen change.
+    pub playlists: HashMap<Screen, Vec<String>>,
+}
+
+impl Config for MusicConfig {
+    const FILE: &'static str = "music.ron";
+
+    fn on_load(&self, world: &mut World) {
+        let asset_server = r!(world.get_resource::<AssetServer>()).clone();
+        let mut music_manager = r!(world.get_resource_mut::<MusicManager>());
+        music_manager.fade_duration = self.fade_duration;
+        music_manager.pause_volume = self.pause_volume;
+        music_manager.playlists = self
+            .playlists
+            .iter()
+            .map(|(&screen, paths)| {
+                (
+                    screen,
+                    paths.iter().map(|path| asset_server.load(path)).collect(),
+                )
+            })
+            .collect();
+    }
+
+    fn count_progress(&self, asset_server: &AssetServer) -> Progress {
+        let mut done = 0;
+        let mut total = 0;
+        for path in self.playlists.values().flatten() {
+            total += 1;
+            done += asset_server
+                .get_path_id(path)
+                .is_some_and(|id| asset_server.is_loaded_with_dependencies(id))
+                as u32;
+        }
+
+        Progress { done, total }
+    }
+}
+
+/// Plays the current screen's playlist, crossfading between tracks.
+#[derive(Resource, Reflect, Debug)]
+#[reflect(Resource)]
+pub struct MusicManager {
+    pub fade_duration: f32,
+    pub pause_volume: f32,
+    pub playlists: HashMap<Screen, Vec<Handle<AudioSource>>>,
+    /// The track that should be playing, if any.
+    requested: Option<Handle<AudioSource>>,
+    /// The index of the requested track in the current playlist, if any.
+    playlist_index: Option<usize>,
+    /// The length of the current playlist.
+    playlist_len: usize,
+    /// The music entity fading in or playing at full volume.
+    current: Option<(Entity, Handle<AudioSource>)>,
+    /// The volume multiplier from pausing, fading towards its target.
+    duck: f32,
+}
+
+impl Configure for MusicManager {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+        app.add_systems(StateFlush, Screen::ANY.on_enter(play_screen_playlist));
+        app.add_systems(
+            Update,
+            (
+                state!(Screen::Splash)
+                    .on_update(MusicConfig::progress.track_progress::<BevyState<Screen>>()),
+                (advance_playlist, crossfade_music)
+                    .chain()
+                    .in_set(UpdateSystems::Update),
+            ),
+        );
+    }
+}
+
+impl Default for MusicManager {
+    fn default() -> Self {
+        Self {
+            fade_duration: 1.0,
+            pause_volume: 1.0,
+            playlists: default(),
+            requested: None,
+            playlist_index: None,
+            playlist_len: 0,
+            current: None,
+            duck: 1.0,
+        }
+    }
+}
+
+impl MusicManager {
+    /// Crossfade to a looping track, outside of any playlist.
+    pub fn play(&mut self, track: Handle<AudioSource>) {
+        self.requested = Some(track);
+        self.playlist_index = None;
+        self.playlist_len = 0;
+    }
+
+    /// Fade out the current track.
+    pub fn stop(&mut self) {
+        self.requested = None;
+        self.playlist_index = None;
+        self.playlist_len = 0;
+    }
+
+    /// The number of tracks in the current playlist, or 0 outside of a playlist.
+    fn playlist_len(&self) -> usize {
+        self.playlist_index.map_or(0, |_| self.playlist_len)
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+pub fn play_screen_playlist(screen: NextRef<Screen>, mut music_manager: ResMut<MusicManager>) {
+    let screen = r!(screen.get());
+    let Some(playlist) = music_manager.playlists.get(screen) else {
+        music_manager.stop();
+        return;
+    };
+
+    // Keep playing the current track if it's in the new playlist.
+    let current = music_manager.current.as_ref().map(|(_, track)| track);
+    let index = current
+        .and_then(|track| playlist.iter().position(|x| x == track))
+        .unwrap_or(0);
+    let track = playlist.get(index).cloned();
+    let len = playlist.len();
+    music_manager.requested = track;
+    music_manager.playlist_index = Some(index);
+    music_manager.playlist_len = len;
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn advance_playlist(
+    screen: CurrentRef<Screen>,
+    mut music_manager: ResMut<MusicManager>,
+    sink_query: Query<&AudioSink>,
+) {
+    let index = rq!(music_manager.playlist_index);
+    let (entity, _) = rq!(music_manager.current.as_ref());
+    let sink = rq!(sink_query.get(*entity));
+    rq!(sink.empty());
+
+    let screen = r!(screen.get());
+    let playlist = r!(music_manager.playlists.get(screen));
+    rq!(!playlist.is_empty());
+    let index = (index + 1) % playlist.len();
+    let track = playlist[index].clone();
+    music_manager.requested = Some(track);
+    music_manager.playlist_index = Some(index);
+    // Start the next track even if it's the same as the last one.
+    music_manager.current = None;
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn crossfade_music(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    pause: CurrentRef<Pause>,
+    mut music_manager: ResMut<MusicManager>,
+    mut fade_query: Query<(Entity, &mut MusicFade, &mut AudioGain)>,
+) {
+    let step = time.delta_secs() / music_manager.fade_duration.max(f32::EPSILON);
+
+    // Start the requested track if it's not playing yet.
+    if music_manager.requested.as_ref() != music_manager.current.as_ref().map(|(_, x)| x) {
+        let track = music_manager.requested.clone();
+        let once = music_manager.playlist_len() > 1;
+        music_manager.current = track.map(|track| {
+            let mut entity =
+                commands.spawn((music_audio(track.clone()), MusicFade(0.0), AudioGain(0.0)));
+            // Let multi-track playlists advance when the track ends.
+            if once {
+                entity.insert(PlaybackSettings::ONCE);
+            }
+            (entity.id(), track)
+        });
+    }
+
+    // Duck the volume while paused.
+    let target = if pause.is_enabled() {
+        music_manager.pause_volume
+    } else {
+        1.0
+    };
+    music_manager.duck += (target - music_manager.duck).clamp(-step, step);
+
+    let current = music_manager.current.as_ref().map(|(entity, _)| *entity);
+    for (entity, mut fade, mut gain) in &mut fade_query {
+        if Some(entity) == current {
+            fade.0 = (fade.0 + step).min(1.0);
+        } else {
+            fade.0 -= step;
+            if fade.0 <= 0.0 {
+                commands.entity(entity).despawn();
+                continue;
+            }
+        }
+        gain.set_if_neq(AudioGain(fade.0 * music_manager.duck));
+    }
+}
+
+/// The fade-in progress of a music track, from `0.0` (silent) to `1.0` (full volume).
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct MusicFade(pub f32);
+
+impl Configure for MusicFade {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+    }
+}
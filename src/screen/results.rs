@@ -0,0 +1,127 @@
+use crate::animation::offset::NodeOffset;
+use crate::animation::tween::NodeOffsetLens;
+use crate::animation::tween::Tween;
+use crate::game::level::CurrentLevel;
+use crate::game::level::LevelConfig;
+use crate::game::level::LevelOutcome;
+use crate::game::level::LevelStats;
+use crate::game::progress::GameProgress;
+use crate::game::progress::record_level_outcome;
+use crate::prelude::*;
+use crate::screen::Screen;
+use crate::screen::ScreenRoot;
+use crate::screen::transition::TransitionStyle;
+use crate::screen::transition::transition_to;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        StateFlush,
+        Screen::Results.on_enter(spawn_results_screen.before(record_level_outcome)),
+    );
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn spawn_results_screen(
+    mut commands: Commands,
+    screen_root: Res<ScreenRoot>,
+    config: ConfigRef<LevelConfig>,
+    current_level: Res<CurrentLevel>,
+    outcome: Option<Res<LevelOutcome>>,
+    stats: Res<LevelStats>,
+    progress: Res<GameProgress>,
+) {
+    let is_win = matches!(outcome.as_deref(), Some(LevelOutcome::Win));
+    let header = if is_win {
+        "[b]Level complete!"
+    } else {
+        "[b]Game over"
+    };
+    // This runs before the outcome is recorded, so the best score is still the previous one.
+    let is_new_best = is_win
+        && progress
+            .best_score(current_level.0)
+            .is_none_or(|best| stats.score > best);
+    let has_next_level = is_win
+        && config
+            .get()
+            .is_some_and(|x| current_level.0 + 1 < x.levels.len());
+
+    commands
+        .entity(screen_root.ui)
+        .with_child(widget::body(children![
+            widget::header(header),
+            (
+                grid(&stats, is_new_best),
+                // Slide the stats up into place.
+                Tween::new(
+                    NodeOffsetLens {
+                        from: NodeOffset::new(Auto, Vw(3.0)),
+                        to: NodeOffset::new(Auto, Vw(0.0)),
+                    },
+                    0.4,
+                )
+                .with_ease(EaseFunction::QuadraticOut),
+            ),
+            widget::row_of_buttons(children![
+                widget::button("Retry", retry_level),
+                (
+                    widget::button("Next level", play_next_level),
+                    InteractionDisabled(!has_next_level),
+                ),
+                widget::button("Title", quit_to_title),
+            ]),
+        ]));
+}
+
+fn grid(stats: &LevelStats, is_new_best: bool) -> impl Bundle {
+    (
+        Name::new("Grid"),
+        Node {
+            display: Display::Grid,
+            margin: UiRect::vertical(Vw(5.0)),
+            row_gap: Vw(1.4),
+            column_gap: Vw(6.0),
+            grid_template_columns: vec![
+                RepeatedGridTrack::flex(1, 1.0),
+                RepeatedGridTrack::flex(1, 1.0),
+            ],
+            ..default()
+        },
+        GridAlignment::columns([JustifySelf::End, JustifySelf::Start]),
+        children![
+            widget::label("Score"),
+            widget::label(if is_new_best {
+                format!("[b]{}[r] (new best!)", stats.score)
+            } else {
+                stats.score.to_string()
+            }),
+            widget::label("Longest chain"),
+            widget::label(stats.longest_chain.to_string()),
+            widget::label("Time"),
+            widget::label(format!("{:.1}s", stats.time.as_secs_f32())),
+        ],
+    )
+}
+
+fn retry_level(_: Trigger<Pointer<Click>>, mut commands: Commands) {
+    commands.spawn(transition_to(Screen::Gameplay, TransitionStyle::Wipe));
+}
+
+fn play_next_level(
+    _: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    config: ConfigRef<LevelConfig>,
+    outcome: Option<Res<LevelOutcome>>,
+    mut current_level: ResMut<CurrentLevel>,
+) {
+    rq!(matches!(outcome.as_deref(), Some(LevelOutcome::Win)));
+    let config = r!(config.get());
+    rq!(current_level.0 + 1 < config.levels.len());
+
+    current_level.0 += 1;
+    commands.spawn(transition_to(Screen::Gameplay, TransitionStyle::Wipe));
+}
+
+fn quit_to_title(_: Trigger<Pointer<Click>>, mut commands: Commands) {
+    commands.spawn(transition_to(Screen::Title, TransitionStyle::Fade));
+}
@@ -5,20 +5,17 @@ use bevy::image::ImageSampler;
 use crate::prelude::*;
 use crate::screen::Screen;
 use crate::screen::ScreenRoot;
-use crate::screen::fade::FADE_IN_SECS;
-use crate::screen::fade::fade_out;
-use crate::screen::title::TitleAssets;
+use crate::screen::transition::TRANSITION_IN_SECS;
+use crate::screen::transition::TransitionStyle;
+use crate::screen::transition::transition_to;
 use crate::screen::wait_in_screen;
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_loading_state(
-        LoadingState::new(Screen::Splash.bevy()).load_collection::<TitleAssets>(),
-    );
     app.add_systems(StateFlush, Screen::Splash.on_enter(spawn_splash_screen));
     app.add_systems(
         Update,
         Screen::Splash.on_update((
-            wait_in_screen(FADE_IN_SECS + SPLASH_SCREEN_MIN_SECS),
+            wait_in_screen(TRANSITION_IN_SECS + SPLASH_SCREEN_MIN_SECS),
             update_splash,
         )),
     );
@@ -64,7 +61,7 @@ fn update_splash(
 
     // Continue to the next screen when ready.
     if done == total {
-        commands.spawn(fade_out(Screen::Title));
+        commands.spawn(transition_to(Screen::Title, TransitionStyle::Fade));
     }
 
     info!("[Frame {}] Booting: {done} / {total}", frame.0);
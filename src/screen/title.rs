@@ -1,5 +1,3 @@
-use crate::core::audio::AudioSettings;
-use crate::core::audio::music_audio;
 use crate::menu::Menu;
 use crate::prelude::*;
 use crate::screen::Screen;
@@ -11,36 +9,6 @@ pub(super) fn plugin(app: &mut App) {
     );
     app.add_systems(
         StateFlush,
-        Screen::Title.on_enter((
-            (Menu::Main.enter(), Menu::acquire).chain(),
-            spawn_title_screen,
-        )),
+        Screen::Title.on_enter((Menu::Main.enter(), Menu::acquire).chain()),
     );
-
-    app.configure::<TitleAssets>();
-}
-
-fn spawn_title_screen(
-    mut commands: Commands,
-    audio_settings: Res<AudioSettings>,
-    assets: Res<TitleAssets>,
-) {
-    commands.spawn((
-        music_audio(&audio_settings, assets.music.clone()),
-        DespawnOnExitState::<Screen>::Recursive,
-    ));
-}
-
-#[derive(AssetCollection, Resource, Reflect, Default)]
-#[reflect(Resource)]
-pub struct TitleAssets {
-    #[asset(path = "audio/music/240376__edtijo__happy-8bit-pixel-adenture.ogg")]
-    music: Handle<AudioSource>,
-}
-
-impl Configure for TitleAssets {
-    fn configure(app: &mut App) {
-        app.register_type::<Self>();
-        app.init_collection::<Self>();
-    }
 }
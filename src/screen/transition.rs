@@ -0,0 +1,303 @@
+use bevy::ui::UiSystem;
+
+use crate::animation::PostColorSystems;
+use crate::prelude::*;
+use crate::screen::Screen;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<(NextTransition, TransitionOverlay, DissolveCell)>();
+}
+
+pub const TRANSITION_IN_SECS: f32 = 0.5;
+const TRANSITION_OUT_SECS: f32 = 0.2;
+
+/// The visual style of a screen [`Transition`].
+#[derive(Reflect, Copy, Clone, Default, Eq, PartialEq, Debug)]
+pub enum TransitionStyle {
+    /// Fade the whole screen.
+    #[default]
+    Fade,
+    /// Sweep across the screen from left to right.
+    Wipe,
+    /// Close and open a circle around the center of the screen.
+    Iris,
+    /// Slide a panel down over the screen and then off the bottom.
+    Slide,
+    /// Cover the screen one block at a time, in random order.
+    Dissolve,
+}
+
+/// A screen transition animation, played out of the current [`Screen`] and into the next.
+#[derive(Reflect, Copy, Clone, Debug)]
+pub struct Transition {
+    pub style: TransitionStyle,
+    /// The duration of the animation out of the current screen, in seconds.
+    pub out_secs: f32,
+    /// The duration of the animation into the next screen, in seconds.
+    pub in_secs: f32,
+    pub ease: EaseFunction,
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Self::new(default())
+    }
+}
+
+impl From<TransitionStyle> for Transition {
+    fn from(style: TransitionStyle) -> Self {
+        Self::new(style)
+    }
+}
+
+impl Transition {
+    pub fn new(style: TransitionStyle) -> Self {
+        Self {
+            style,
+            out_secs: TRANSITION_OUT_SECS,
+            in_secs: TRANSITION_IN_SECS,
+            ease: EaseFunction::Linear,
+        }
+    }
+
+    pub fn with_durations(mut self, out_secs: f32, in_secs: f32) -> Self {
+        self.out_secs = out_secs;
+        self.in_secs = in_secs;
+        self
+    }
+
+    pub fn with_ease(mut self, ease: EaseFunction) -> Self {
+        self.ease = ease;
+        self
+    }
+}
+
+/// The [`Transition`] to play when entering the next [`Screen`].
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct NextTransition(Transition);
+
+impl Configure for NextTransition {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+        app.add_systems(StateFlush, Screen::ANY.on_enter(spawn_transition_in));
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn spawn_transition_in(mut commands: Commands, mut next: ResMut<NextTransition>) {
+    // Only the next screen uses this transition, unless another one is requested.
+    let transition = core::mem::take(&mut next.0);
+    commands.spawn(transition_in(transition));
+}
+
+/// A screen transition animation for entering the current [`Screen`].
+pub fn transition_in(transition: impl Into<Transition>) -> impl Bundle {
+    let transition = transition.into();
+    (
+        widget::overlay(1000),
+        TransitionOverlay::new(transition, None),
+        Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+            spawn_panels(parent, transition.style)
+        })),
+    )
+}
+
+/// A screen transition animation for exiting the current [`Screen`] into another.
+///
+/// The overlay blocks interaction until the transition finishes.
+pub fn transition_to(to_screen: Screen, transition: impl Into<Transition>) -> impl Bundle {
+    let transition = transition.into();
+    (
+        widget::blocking_overlay(1000),
+        TransitionOverlay::new(transition, Some(to_screen)),
+        Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+            spawn_panels(parent, transition.style)
+        })),
+    )
+}
+
+/// The number of columns and rows of blocks in a [`TransitionStyle::Dissolve`].
+const DISSOLVE_GRID: UVec2 = UVec2::new(16, 9);
+
+/// The diameter of the [`TransitionStyle::Iris`] ring, in `VMax`.
+///
+/// This must be large enough for the open iris to reveal the whole screen.
+const IRIS_SIZE: f32 = 300.0;
+
+fn spawn_panels(parent: &mut ChildSpawner, style: TransitionStyle) {
+    match style {
+        TransitionStyle::Fade | TransitionStyle::Wipe | TransitionStyle::Slide => {
+            parent.spawn((
+                Name::new("TransitionPanel"),
+                Node::DEFAULT.full_size().abs(),
+                ThemeColor::Body.set::<BackgroundColor>(),
+                Pickable::IGNORE,
+            ));
+        },
+        TransitionStyle::Iris => {
+            parent.spawn((
+                Name::new("TransitionIris"),
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Percent(50.0),
+                    top: Percent(50.0),
+                    width: VMax(IRIS_SIZE),
+                    height: VMax(IRIS_SIZE),
+                    margin: UiRect {
+                        left: VMax(-IRIS_SIZE / 2.0),
+                        top: VMax(-IRIS_SIZE / 2.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+                BorderRadius::MAX,
+                ThemeColor::Body.set::<BorderColor>(),
+                Pickable::IGNORE,
+            ));
+        },
+        TransitionStyle::Dissolve => {
+            let mut rng = thread_rng();
+            parent
+                .spawn((
+                    Name::new("TransitionGrid"),
+                    Node {
+                        display: Display::Grid,
+                        grid_template_columns: RepeatedGridTrack::flex(DISSOLVE_GRID.x as _, 1.0),
+                        grid_template_rows: RepeatedGridTrack::flex(DISSOLVE_GRID.y as _, 1.0),
+                        ..Node::DEFAULT.full_size().abs()
+                    },
+                    Pickable::IGNORE,
+                ))
+                .with_children(|parent| {
+                    for _ in 0..DISSOLVE_GRID.x * DISSOLVE_GRID.y {
+                        parent.spawn((
+                            Name::new("TransitionBlock"),
+                            Node::default(),
+                            ThemeColor::Body.set::<BackgroundColor>(),
+                            DissolveCell(rng.gen_range(0.0..1.0)),
+                            Pickable::IGNORE,
+                        ));
+                    }
+                });
+        },
+    }
+}
+
+/// A running screen [`Transition`].
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct TransitionOverlay {
+    transition: Transition,
+    /// The screen to enter when finished, or `None` if entering the current screen.
+    to_screen: Option<Screen>,
+    elapsed: f32,
+}
+
+impl Configure for TransitionOverlay {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            PostUpdate,
+            apply_transition
+                .in_set(PostColorSystems::Blend)
+                .before(UiSystem::Layout),
+        );
+    }
+}
+
+impl TransitionOverlay {
+    fn new(transition: Transition, to_screen: Option<Screen>) -> Self {
+        Self {
+            transition,
+            to_screen,
+            elapsed: 0.0,
+        }
+    }
+
+    fn duration(&self) -> f32 {
+        if self.to_screen.is_some() {
+            self.transition.out_secs
+        } else {
+            self.transition.in_secs
+        }
+    }
+
+    /// The fraction of the screen to cover, from `0.0` to `1.0`.
+    fn coverage(&self) -> f32 {
+        let t = (self.elapsed / self.duration().max(f32::EPSILON)).clamp(0.0, 1.0);
+        let t = self.transition.ease.sample_clamped(t);
+        if self.to_screen.is_some() { t } else { 1.0 - t }
+    }
+}
+
+/// A block in a [`TransitionStyle::Dissolve`] that appears once coverage passes its threshold.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct DissolveCell(f32);
+
+impl Configure for DissolveCell {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn apply_transition(
+    time: Res<Time<Real>>,
+    mut late: LateCommands,
+    mut screen: NextMut<Screen>,
+    mut next_transition: ResMut<NextTransition>,
+    mut transition_query: Query<(Entity, &mut TransitionOverlay, &Children)>,
+    mut panel_query: Query<
+        (&mut Node, &mut BackgroundColor, Option<&Children>),
+        Without<DissolveCell>,
+    >,
+    mut cell_query: Query<(&DissolveCell, &mut BackgroundColor)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut overlay, children) in &mut transition_query {
+        let coverage = overlay.coverage();
+        let entering = overlay.to_screen.is_none();
+        for &child in children {
+            let (mut node, mut color, cells) = c!(panel_query.get_mut(child));
+            match overlay.transition.style {
+                TransitionStyle::Fade => color.0.set_alpha(coverage),
+                TransitionStyle::Wipe => {
+                    node.left = Percent(if entering {
+                        100.0 * (1.0 - coverage)
+                    } else {
+                        0.0
+                    });
+                    node.width = Percent(100.0 * coverage);
+                },
+                TransitionStyle::Slide => {
+                    let offset = 100.0 * (1.0 - coverage);
+                    node.top = Percent(if entering { offset } else { -offset });
+                },
+                TransitionStyle::Iris => {
+                    let hole = (1.0 - coverage) * IRIS_SIZE / 2.0;
+                    node.border = UiRect::all(VMax((IRIS_SIZE - hole) / 2.0));
+                },
+                TransitionStyle::Dissolve => {
+                    for &cell in cells.into_iter().flatten() {
+                        let (threshold, mut color) = c!(cell_query.get_mut(cell));
+                        color
+                            .0
+                            .set_alpha(if coverage > threshold.0 { 1.0 } else { 0.0 });
+                    }
+                },
+            }
+        }
+
+        if overlay.elapsed >= overlay.duration() {
+            if let Some(to_screen) = overlay.to_screen {
+                next_transition.0 = overlay.transition;
+                screen.trigger().enter(to_screen);
+            }
+            late.commands().entity(entity).despawn();
+        }
+        overlay.elapsed += dt;
+    }
+}
@@ -3,6 +3,7 @@ use std::ops::Index;
 use bevy::ecs::component::Mutable;
 
 use crate::prelude::*;
+use crate::theme::interaction::UiSfxConfig;
 
 pub(super) fn plugin(app: &mut App) {
     // Default to Bevy logo grey instead of Bevy website code block grey.
@@ -22,6 +23,8 @@ pub(super) fn plugin(app: &mut App) {
 #[serde(deny_unknown_fields)]
 pub struct ThemeConfig {
     pub colors: ThemeColorList,
+    pub sfx_hover: UiSfxConfig,
+    pub sfx_click: UiSfxConfig,
 }
 
 impl Config for ThemeConfig {
@@ -34,7 +37,7 @@ impl Config for ThemeConfig {
 
 // Note: The length of this array MUST equal the number of `ThemeColor` variants.
 #[derive(Reflect, Serialize, Deserialize)]
-pub struct ThemeColorList([Color; 11]);
+pub struct ThemeColorList([Color; 15]);
 
 impl Index<ThemeColor> for ThemeColorList {
     type Output = Color;
@@ -45,7 +48,7 @@ impl Index<ThemeColor> for ThemeColorList {
 }
 
 /// See: <https://getbootstrap.com/docs/5.3/customize/color/>.
-#[derive(Reflect, Clone, Copy, Default)]
+#[derive(Reflect, Serialize, Deserialize, Clone, Copy, Default, Debug)]
 pub enum ThemeColor {
     // Absolute colors.
     #[default]
@@ -65,6 +68,12 @@ pub enum ThemeColor {
     // Other UI colors.
     Popup,
     Overlay,
+
+    // Gameplay colors.
+    Player,
+    Bomb,
+    Trigger,
+    Wall,
 }
 
 impl ThemeColor {
@@ -0,0 +1,96 @@
+use bevy::picking::backend::HitData;
+use bevy::picking::pointer::Location;
+use bevy::picking::pointer::PointerId;
+use bevy::render::camera::NormalizedRenderTarget;
+use bevy::window::WindowRef;
+
+use crate::core::camera::CameraRoot;
+use crate::core::window::WindowRoot;
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<(Focus, Focusable)>();
+}
+
+/// The entity with keyboard / gamepad focus, if any.
+#[derive(Resource, Reflect, Default, Debug)]
+#[reflect(Resource)]
+pub struct Focus(pub Option<Entity>);
+
+impl Configure for Focus {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+        app.add_systems(
+            Update,
+            apply_focus
+                .in_set(UpdateSystems::SyncLate)
+                .run_if(resource_changed::<Self>),
+        );
+    }
+}
+
+impl Focus {
+    pub fn clear(mut focus: ResMut<Self>) {
+        focus.0 = None;
+    }
+}
+
+/// Show the focused [`Focusable`] (or its children, for a selector) as hovered.
+#[cfg_attr(feature = "native_dev", hot)]
+fn apply_focus(
+    focus: Res<Focus>,
+    mut focused_query: Query<(Entity, Option<&ChildOf>, &mut InteractionFocused)>,
+) {
+    for (entity, child_of, mut focused) in &mut focused_query {
+        let is_focused = focus
+            .0
+            .is_some_and(|x| x == entity || child_of.is_some_and(|y| y.parent() == x));
+        focused.set_if_neq(InteractionFocused(is_focused));
+    }
+}
+
+/// An entity that can receive [`Focus`] through directional navigation.
+#[derive(Component, Reflect, Copy, Clone, Default, Eq, PartialEq, Debug)]
+#[reflect(Component)]
+pub enum Focusable {
+    /// Confirming clicks the entity.
+    #[default]
+    Button,
+    /// Left and right click the first and last child instead of moving focus.
+    Selector,
+}
+
+impl Configure for Focusable {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+    }
+}
+
+/// Trigger a [`Pointer<Click>`] on a UI entity as if it had been clicked with the mouse.
+///
+/// Does nothing if the entity is [`InteractionDisabled`].
+pub fn click(world: &mut World, entity: Entity) {
+    rq!(!matches!(
+        world.get::<InteractionDisabled>(entity),
+        Some(InteractionDisabled(true)),
+    ));
+    let camera = r!(world.get_resource::<CameraRoot>()).primary;
+    let window = r!(world.get_resource::<WindowRoot>()).primary;
+    let position = r!(world.get::<GlobalTransform>(entity)).translation().xy();
+    let target = NormalizedRenderTarget::Window(r!(WindowRef::Entity(window).normalize(None)));
+
+    world.trigger_targets(
+        Pointer::new(
+            PointerId::Mouse,
+            Location { target, position },
+            entity,
+            Click {
+                button: PointerButton::Primary,
+                hit: HitData::new(camera, 0.0, None, None),
+                duration: Duration::ZERO,
+            },
+        ),
+        entity,
+    );
+}
@@ -1,26 +1,30 @@
 use bevy::ecs::component::Mutable;
 use bevy::reflect::GetTypeRegistration;
 use bevy::reflect::Typed;
+use rand::rngs::StdRng;
 
 use crate::animation::offset::NodeOffset;
-use crate::core::audio::AudioSettings;
 use crate::core::audio::ui_audio;
 use crate::prelude::*;
 use crate::theme::ThemeAssets;
+use crate::theme::color::ThemeConfig;
 
 pub(super) fn plugin(app: &mut App) {
     app.configure::<(
         Previous<Interaction>,
         InteractionDisabled,
+        InteractionFocused,
         InteractionTheme<ThemeColorFor<BackgroundColor>>,
         InteractionTheme<NodeOffset>,
         TargetInteractionTheme<ThemeColorForText>,
         TargetInteractionTheme<NodeOffset>,
         InteractionSfx,
+        UiSfx,
+        UiSfxPlayer,
     )>();
 }
 
-#[derive(Component, Reflect)]
+#[derive(Component, Reflect, Copy, Clone, Eq, PartialEq, Debug)]
 #[reflect(Component)]
 pub struct InteractionDisabled(pub bool);
 
@@ -30,10 +34,21 @@ impl Configure for InteractionDisabled {
     }
 }
 
+/// Whether this entity has keyboard / gamepad focus, to be displayed as hovered.
+#[derive(Component, Reflect, Copy, Clone, Default, Eq, PartialEq, Debug)]
+#[reflect(Component)]
+pub struct InteractionFocused(pub bool);
+
+impl Configure for InteractionFocused {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+    }
+}
+
 /// A table of values to set a component to by interaction state.
 #[derive(Component, Reflect, Default)]
 #[reflect(Component)]
-#[require(Interaction, Previous<Interaction>)]
+#[require(Interaction, Previous<Interaction>, InteractionFocused)]
 pub struct InteractionTheme<C: Component<Mutability = Mutable> + Clone> {
     pub none: C,
     pub hovered: C,
@@ -58,6 +73,7 @@ fn apply_interaction_theme<C: Component<Mutability = Mutable> + Clone>(
     mut interaction_query: Query<
         (
             Option<&InteractionDisabled>,
+            Option<&InteractionFocused>,
             &Previous<Interaction>,
             &Interaction,
             &InteractionTheme<C>,
@@ -65,12 +81,13 @@ fn apply_interaction_theme<C: Component<Mutability = Mutable> + Clone>(
         ),
         Or<(
             Changed<InteractionDisabled>,
+            Changed<InteractionFocused>,
             Changed<Previous<Interaction>>,
             Changed<Interaction>,
         )>,
     >,
 ) {
-    for (is_disabled, previous, current, table, mut value) in &mut interaction_query {
+    for (is_disabled, is_focused, previous, current, table, mut value) in &mut interaction_query {
         // Add 1 frame of delay when going from pressed -> hovered.
         cq!(!matches!(
             (previous.0, current),
@@ -82,6 +99,9 @@ fn apply_interaction_theme<C: Component<Mutability = Mutable> + Clone>(
             &table.disabled
         } else {
             match current {
+                Interaction::None if matches!(is_focused, Some(InteractionFocused(true))) => {
+                    &table.hovered
+                },
                 Interaction::None => &table.none,
                 Interaction::Hovered => &table.hovered,
                 Interaction::Pressed => &table.pressed,
@@ -120,18 +140,20 @@ fn apply_target_interaction_theme<C: Component<Mutability = Mutable> + Clone>(
     interaction_query: Query<
         (
             Option<&InteractionDisabled>,
+            Option<&InteractionFocused>,
             &Previous<Interaction>,
             &Interaction,
         ),
         Or<(
             Changed<InteractionDisabled>,
+            Changed<InteractionFocused>,
             Changed<Previous<Interaction>>,
             Changed<Interaction>,
         )>,
     >,
 ) {
     for (table, mut value) in &mut table_query {
-        let (is_disabled, previous, current) = cq!(interaction_query.get(table.target));
+        let (is_disabled, is_focused, previous, current) = cq!(interaction_query.get(table.target));
         // Add 1 frame of delay when going from pressed -> hovered.
         cq!(!matches!(
             (previous.0, current),
@@ -143,6 +165,9 @@ fn apply_target_interaction_theme<C: Component<Mutability = Mutable> + Clone>(
             &table.disabled
         } else {
             match current {
+                Interaction::None if matches!(is_focused, Some(InteractionFocused(true))) => {
+                    &table.hovered
+                },
                 Interaction::None => &table.none,
                 Interaction::Hovered => &table.hovered,
                 Interaction::Pressed => &table.pressed,
@@ -167,28 +192,203 @@ impl Configure for InteractionSfx {
 
 fn play_hover_sfx(
     trigger: Trigger<Pointer<Over>>,
-    audio_settings: Res<AudioSettings>,
+    mut commands: Commands,
     assets: Res<ThemeAssets>,
+    config: ConfigRef<ThemeConfig>,
+    time: Res<Time<Real>>,
+    mut player: ResMut<UiSfxPlayer>,
     sfx_query: Query<Option<&InteractionDisabled>, With<InteractionSfx>>,
-    mut commands: Commands,
+    playing_query: Query<&UiSfx>,
 ) {
     let target = r!(trigger.get_target());
     let disabled = rq!(sfx_query.get(target));
     rq!(!matches!(disabled, Some(InteractionDisabled(true))));
 
-    commands.spawn(ui_audio(&audio_settings, assets.sfx_hover.clone()));
+    let config = &r!(config.get()).sfx_hover;
+    let playing = playing_query.iter().filter(|&&x| x == UiSfx::Hover).count();
+    let (speed, gain) = rq!(player.try_play(UiSfx::Hover, config, time.elapsed(), playing));
+    commands.spawn((
+        ui_audio(assets.sfx_hover.clone(), speed, gain),
+        UiSfx::Hover,
+    ));
 }
 
 fn play_click_sfx(
     trigger: Trigger<Pointer<Click>>,
-    audio_settings: Res<AudioSettings>,
+    mut commands: Commands,
     assets: Res<ThemeAssets>,
+    config: ConfigRef<ThemeConfig>,
+    time: Res<Time<Real>>,
+    mut player: ResMut<UiSfxPlayer>,
     sfx_query: Query<Option<&InteractionDisabled>, With<InteractionSfx>>,
-    mut commands: Commands,
+    playing_query: Query<&UiSfx>,
 ) {
     let target = r!(trigger.get_target());
     let disabled = rq!(sfx_query.get(target));
     rq!(!matches!(disabled, Some(InteractionDisabled(true))));
 
-    commands.spawn(ui_audio(&audio_settings, assets.sfx_click.clone()));
+    let config = &r!(config.get()).sfx_click;
+    let playing = playing_query.iter().filter(|&&x| x == UiSfx::Click).count();
+    let (speed, gain) = rq!(player.try_play(UiSfx::Click, config, time.elapsed(), playing));
+    commands.spawn((
+        ui_audio(assets.sfx_click.clone(), speed, gain),
+        UiSfx::Click,
+    ));
+}
+
+/// Variation and anti-spam settings for a UI sound effect.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct UiSfxConfig {
+    /// The range to randomize playback speed (and pitch) within.
+    pub speed: (f32, f32),
+    /// The range to randomize volume within.
+    pub volume: (f32, f32),
+    /// The minimum time between plays, in seconds.
+    pub cooldown: f32,
+    /// The maximum number of instances that can play at once.
+    pub max_polyphony: usize,
+}
+
+/// A UI sound effect, and a marker for its playing instances.
+#[derive(Component, Reflect, Copy, Clone, Eq, PartialEq, Debug)]
+#[reflect(Component)]
+pub enum UiSfx {
+    Hover,
+    Click,
+}
+
+impl Configure for UiSfx {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+    }
+}
+
+/// Random variation and cooldown tracking for [`UiSfx`].
+#[derive(Resource, Reflect)]
+#[reflect(Resource)]
+pub struct UiSfxPlayer {
+    #[reflect(ignore, default = "entropy_rng")]
+    rng: StdRng,
+    /// The time each sound was last played, indexed by [`UiSfx`].
+    last_played: [Option<Duration>; 2],
+}
+
+impl Configure for UiSfxPlayer {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+    }
+}
+
+impl Default for UiSfxPlayer {
+    fn default() -> Self {
+        Self::new(entropy_rng())
+    }
+}
+
+fn entropy_rng() -> StdRng {
+    StdRng::from_entropy()
+}
+
+impl UiSfxPlayer {
+    fn new(rng: StdRng) -> Self {
+        Self {
+            rng,
+            last_played: [None; 2],
+        }
+    }
+
+    /// Create a player with deterministic variation.
+    pub fn seeded(seed: u64) -> Self {
+        Self::new(StdRng::seed_from_u64(seed))
+    }
+
+    /// Roll the speed and volume for a sound, or return `None` if it's on cooldown or
+    /// at its polyphony limit.
+    pub fn try_play(
+        &mut self,
+        sfx: UiSfx,
+        config: &UiSfxConfig,
+        now: Duration,
+        playing: usize,
+    ) -> Option<(f32, f32)> {
+        if playing >= config.max_polyphony {
+            return None;
+        }
+        let last_played = &mut self.last_played[sfx as usize];
+        if last_played.is_some_and(|x| (now - x).as_secs_f32() < config.cooldown) {
+            return None;
+        }
+        *last_played = Some(now);
+
+        let speed = config
+            .speed
+            .0
+            .lerp(config.speed.1, self.rng.gen_range(0.0..=1.0));
+        let volume = config
+            .volume
+            .0
+            .lerp(config.volume.1, self.rng.gen_range(0.0..=1.0));
+        Some((speed, volume))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> UiSfxConfig {
+        UiSfxConfig {
+            speed: (0.9, 1.5),
+            volume: (0.5, 1.0),
+            cooldown: 0.1,
+            max_polyphony: 2,
+        }
+    }
+
+    #[test]
+    fn test_seeded_variation() {
+        let config = config();
+        let mut a = UiSfxPlayer::seeded(7);
+        let mut b = UiSfxPlayer::seeded(7);
+        for i in 0..10 {
+            let now = Duration::from_secs(i);
+            let x = a.try_play(UiSfx::Hover, &config, now, 0).unwrap();
+            assert_eq!(Some(x), b.try_play(UiSfx::Hover, &config, now, 0));
+            assert!((0.9..=1.5).contains(&x.0));
+            assert!((0.5..=1.0).contains(&x.1));
+        }
+    }
+
+    #[test]
+    fn test_cooldown_and_polyphony() {
+        let config = config();
+        let mut player = UiSfxPlayer::seeded(0);
+        assert!(
+            player
+                .try_play(UiSfx::Hover, &config, Duration::ZERO, 0)
+                .is_some()
+        );
+        assert!(
+            player
+                .try_play(UiSfx::Hover, &config, Duration::from_millis(50), 0)
+                .is_none()
+        );
+        assert!(
+            player
+                .try_play(UiSfx::Click, &config, Duration::from_millis(50), 0)
+                .is_some()
+        );
+        assert!(
+            player
+                .try_play(UiSfx::Hover, &config, Duration::from_millis(150), 2)
+                .is_none()
+        );
+        assert!(
+            player
+                .try_play(UiSfx::Hover, &config, Duration::from_millis(150), 1)
+                .is_some()
+        );
+    }
 }
@@ -3,8 +3,11 @@
 #![allow(dead_code)]
 
 pub mod color;
+pub mod focus;
 pub mod grid;
 pub mod interaction;
+pub mod scroll;
+pub mod setting;
 pub mod text;
 pub mod tooltip;
 pub mod widget;
@@ -14,10 +17,14 @@ pub mod prelude {
     pub use super::color::ThemeColor;
     pub use super::color::ThemeColorFor;
     pub use super::color::ThemeColorForText;
+    pub use super::focus::Focus;
+    pub use super::focus::Focusable;
     pub use super::grid::GridAlignment;
     pub use super::interaction::InteractionDisabled;
+    pub use super::interaction::InteractionFocused;
     pub use super::interaction::InteractionSfx;
     pub use super::interaction::InteractionTheme;
+    pub use super::setting::SettingSelector;
     pub use super::text::BOLD_FONT_HANDLE;
     pub use super::text::DynamicFontSize;
     pub use super::text::FONT_HANDLE;
@@ -34,8 +41,11 @@ pub(super) fn plugin(app: &mut App) {
 
     app.add_plugins((
         color::plugin,
+        focus::plugin,
         grid::plugin,
         interaction::plugin,
+        scroll::plugin,
+        setting::plugin,
         text::plugin,
         tooltip::plugin,
     ));
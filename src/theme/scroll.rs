@@ -0,0 +1,94 @@
+use bevy::input::mouse::MouseScrollUnit;
+use bevy::input::mouse::MouseWheel;
+use bevy::picking::hover::HoverMap;
+use bevy::picking::pointer::PointerId;
+
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<IsScrollable>();
+}
+
+/// A node that scrolls vertically with the mouse wheel and to keep [`Focus`] in view.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+#[require(ScrollPosition)]
+pub struct IsScrollable;
+
+impl Configure for IsScrollable {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            Update,
+            (
+                scroll_with_mouse_wheel.in_set(UpdateSystems::RecordInput),
+                scroll_to_focus
+                    .in_set(UpdateSystems::SyncLate)
+                    .run_if(resource_changed::<Focus>),
+            ),
+        );
+    }
+}
+
+/// The distance to scroll per line of mouse wheel input, in logical pixels.
+const LINE_HEIGHT: f32 = 32.0;
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn scroll_with_mouse_wheel(
+    mut wheel_events: EventReader<MouseWheel>,
+    hover_map: Res<HoverMap>,
+    parent_query: Query<&ChildOf>,
+    mut scroll_query: Query<&mut ScrollPosition, With<IsScrollable>>,
+) {
+    let delta = wheel_events
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y * LINE_HEIGHT,
+            MouseScrollUnit::Pixel => event.y,
+        })
+        .sum::<f32>();
+    rq!(delta != 0.0);
+    let hovered = rq!(hover_map.get(&PointerId::Mouse));
+
+    // Scroll the nearest scrollable ancestor of each hovered entity.
+    for &entity in hovered.keys() {
+        let scrollable = cq!(core::iter::once(entity)
+            .chain(parent_query.iter_ancestors(entity))
+            .find(|&x| scroll_query.contains(x)));
+        let mut scroll = c!(scroll_query.get_mut(scrollable));
+        // The layout clamps the offset to the content size.
+        scroll.offset_y = (scroll.offset_y - delta).max(0.0);
+    }
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn scroll_to_focus(
+    focus: Res<Focus>,
+    parent_query: Query<&ChildOf>,
+    node_query: Query<(&ComputedNode, &GlobalTransform)>,
+    mut scroll_query: Query<&mut ScrollPosition, With<IsScrollable>>,
+) {
+    let focused = rq!(focus.0);
+    let scrollable = rq!(parent_query
+        .iter_ancestors(focused)
+        .find(|&x| scroll_query.contains(x)));
+    let (focused_node, focused_gt) = r!(node_query.get(focused));
+    let (scroll_node, scroll_gt) = r!(node_query.get(scrollable));
+
+    // Compare the vertical extents of the focused node and the scrollable node, in physical pixels.
+    let half_height = focused_node.size().y / 2.0;
+    let top = focused_gt.translation().y - half_height;
+    let bottom = focused_gt.translation().y + half_height;
+    let scroll_top = scroll_gt.translation().y - scroll_node.size().y / 2.0;
+    let scroll_bottom = scroll_gt.translation().y + scroll_node.size().y / 2.0;
+    let delta = if top < scroll_top {
+        top - scroll_top
+    } else if bottom > scroll_bottom {
+        bottom - scroll_bottom
+    } else {
+        return;
+    };
+
+    let mut scroll = r!(scroll_query.get_mut(scrollable));
+    scroll.offset_y = (scroll.offset_y + delta * scroll_node.inverse_scale_factor()).max(0.0);
+}
@@ -0,0 +1,337 @@
+use core::any::TypeId;
+use core::ops::RangeInclusive;
+use std::borrow::Cow;
+
+use bevy::reflect::DynamicEnum;
+use bevy::reflect::DynamicVariant;
+use bevy::reflect::ReflectMut;
+use bevy::reflect::ReflectRef;
+
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<SettingSelector>();
+}
+
+/// A [`widget::selector`] bound to a field of a settings resource through reflection.
+///
+/// The resource must be registered with `#[reflect(Resource)]`.
+#[derive(Component, Reflect, Clone)]
+#[reflect(Component)]
+pub struct SettingSelector {
+    /// The type of the settings resource.
+    pub resource: TypeId,
+    /// The reflect path to the field within the settings resource.
+    pub path: Cow<'static, str>,
+    #[reflect(ignore, default = "default_kind")]
+    pub kind: SettingKind,
+}
+
+impl Configure for SettingSelector {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            Update,
+            update_setting_selectors
+                .in_set(UpdateSystems::Update)
+                .run_if(any_with_component::<Self>),
+        );
+    }
+}
+
+fn default_kind() -> SettingKind {
+    SettingKind::Enum {
+        format: str::to_string,
+    }
+}
+
+impl SettingSelector {
+    /// Step an `f32` field within a range.
+    pub fn float<R: Resource>(
+        path: impl Into<Cow<'static, str>>,
+        step: f32,
+        range: RangeInclusive<f32>,
+        format: fn(f32) -> String,
+    ) -> Self {
+        Self {
+            resource: TypeId::of::<R>(),
+            path: path.into(),
+            kind: SettingKind::Float {
+                step,
+                min: *range.start(),
+                max: *range.end(),
+                format,
+            },
+        }
+    }
+
+    /// Toggle a `bool` field between "On" and "Off".
+    pub fn toggle<R: Resource>(path: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            resource: TypeId::of::<R>(),
+            path: path.into(),
+            kind: SettingKind::Bool {
+                format: |x| if x { "On" } else { "Off" }.to_string(),
+            },
+        }
+    }
+
+    /// Cycle through the variants of a fieldless enum field, displayed by name.
+    pub fn cycle<R: Resource>(path: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            resource: TypeId::of::<R>(),
+            path: path.into(),
+            kind: SettingKind::Enum {
+                format: str::to_string,
+            },
+        }
+    }
+
+    /// Step the bound field by `delta` steps.
+    fn step(&self, world: &mut World, delta: i32) {
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        let reflect_resource = r!(registry.get_type_data::<ReflectResource>(self.resource));
+        let mut resource = r!(reflect_resource.reflect_mut(world));
+        let field = r!(resource.reflect_path_mut(&*self.path));
+
+        match self.kind {
+            SettingKind::Float { step, min, max, .. } => {
+                let value = r!(field.try_downcast_mut::<f32>());
+                // Snap to the nearest step to avoid accumulating rounding errors.
+                *value = (((*value / step).round() + delta as f32) * step).clamp(min, max);
+            },
+            SettingKind::Bool { .. } => {
+                let value = r!(field.try_downcast_mut::<bool>());
+                *value = !*value;
+            },
+            SettingKind::Enum { .. } => {
+                let ReflectMut::Enum(value) = field.reflect_mut() else {
+                    return;
+                };
+                let info = r!(value.get_represented_enum_info());
+                let index = (value.variant_index() as i32 + delta)
+                    .rem_euclid(info.variant_len() as i32) as usize;
+                let name = r!(info.variant_at(index)).name();
+                value.apply(&DynamicEnum::new(name, DynamicVariant::Unit));
+            },
+        }
+    }
+
+    /// The label to display and whether the left / right buttons should be disabled.
+    fn display(&self, world: &World) -> Option<(String, bool, bool)> {
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let reflect_resource = registry.get_type_data::<ReflectResource>(self.resource)?;
+        let resource = reflect_resource.reflect(world).ok()?;
+        let field = resource.reflect_path(&*self.path).ok()?;
+
+        Some(match self.kind {
+            SettingKind::Float {
+                min, max, format, ..
+            } => {
+                let value = *field.try_downcast_ref::<f32>()?;
+                (
+                    format(value),
+                    value <= min + f32::EPSILON,
+                    value >= max - f32::EPSILON,
+                )
+            },
+            SettingKind::Bool { format } => {
+                (format(*field.try_downcast_ref::<bool>()?), false, false)
+            },
+            SettingKind::Enum { format } => {
+                let ReflectRef::Enum(value) = field.reflect_ref() else {
+                    return None;
+                };
+                (format(value.variant_name()), false, false)
+            },
+        })
+    }
+}
+
+/// How a [`SettingSelector`] steps and displays its field.
+#[derive(Copy, Clone)]
+pub enum SettingKind {
+    /// An `f32` stepped within a range.
+    Float {
+        step: f32,
+        min: f32,
+        max: f32,
+        format: fn(f32) -> String,
+    },
+    /// A `bool` toggled by either button.
+    Bool { format: fn(bool) -> String },
+    /// A fieldless enum cycled through its variants, wrapping around.
+    Enum { format: fn(&str) -> String },
+}
+
+/// Format an `f32` as a percentage, where `1.0` is 100%.
+pub fn format_percent(value: f32) -> String {
+    format!("{:.0}%", value * 100.0)
+}
+
+#[cfg_attr(feature = "native_dev", hot)]
+fn update_setting_selectors(world: &mut World) {
+    let mut selector_query = world.query::<(&SettingSelector, &Children)>();
+    let updates = selector_query
+        .iter(world)
+        .filter_map(|(selector, children)| {
+            let (text, left_disabled, right_disabled) = selector.display(world)?;
+            Some((children.to_vec(), text, left_disabled, right_disabled))
+        })
+        .collect::<Vec<_>>();
+
+    for (children, text, left_disabled, right_disabled) in updates {
+        let left = *c!(children.first());
+        c!(world.get_mut::<InteractionDisabled>(left))
+            .set_if_neq(InteractionDisabled(left_disabled));
+
+        let mid = *c!(children.get(1));
+        let label = *c!(c!(world.get::<Children>(mid)).first());
+        c!(world.get_mut::<RichText>(label)).sections = parse_rich(text);
+
+        let right = *c!(children.get(2));
+        c!(world.get_mut::<InteractionDisabled>(right))
+            .set_if_neq(InteractionDisabled(right_disabled));
+    }
+}
+
+pub(super) fn decrement_setting(
+    trigger: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    parent_query: Query<&ChildOf>,
+    selector_query: Query<&SettingSelector>,
+) {
+    let target = r!(trigger.get_target());
+    let parent = r!(parent_query.get(target)).parent();
+    let selector = r!(selector_query.get(parent)).clone();
+    commands.queue(move |world: &mut World| selector.step(world, -1));
+}
+
+pub(super) fn increment_setting(
+    trigger: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    parent_query: Query<&ChildOf>,
+    selector_query: Query<&SettingSelector>,
+) {
+    let target = r!(trigger.get_target());
+    let parent = r!(parent_query.get(target)).parent();
+    let selector = r!(selector_query.get(parent)).clone();
+    commands.queue(move |world: &mut World| selector.step(world, 1));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct TestSettings {
+        volume: [f32; 2],
+        enabled: bool,
+        mode: TestMode,
+    }
+
+    #[derive(Reflect, Copy, Clone, Default, Eq, PartialEq, Debug)]
+    enum TestMode {
+        #[default]
+        A,
+        B,
+        C,
+    }
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<TestSettings>();
+        world.init_resource::<TestSettings>();
+        world
+    }
+
+    fn volume(world: &World) -> f32 {
+        world.resource::<TestSettings>().volume[1]
+    }
+
+    #[test]
+    fn test_float() {
+        let mut world = world();
+        let selector =
+            SettingSelector::float::<TestSettings>("volume[1]", 0.1, 0.0..=1.0, format_percent);
+
+        selector.step(&mut world, 3);
+        assert!((volume(&world) - 0.3).abs() < 1e-6);
+        assert_eq!(world.resource::<TestSettings>().volume[0], 0.0);
+        assert_eq!(
+            selector.display(&world),
+            Some(("30%".to_string(), false, false)),
+        );
+
+        selector.step(&mut world, 10);
+        assert_eq!(volume(&world), 1.0);
+        assert_eq!(
+            selector.display(&world),
+            Some(("100%".to_string(), false, true)),
+        );
+
+        selector.step(&mut world, -20);
+        assert_eq!(volume(&world), 0.0);
+        assert_eq!(
+            selector.display(&world),
+            Some(("0%".to_string(), true, false)),
+        );
+    }
+
+    #[test]
+    fn test_toggle() {
+        let mut world = world();
+        let selector = SettingSelector::toggle::<TestSettings>("enabled");
+
+        selector.step(&mut world, 1);
+        assert!(world.resource::<TestSettings>().enabled);
+        assert_eq!(
+            selector.display(&world),
+            Some(("On".to_string(), false, false)),
+        );
+
+        selector.step(&mut world, -1);
+        assert!(!world.resource::<TestSettings>().enabled);
+    }
+
+    #[test]
+    fn test_cycle() {
+        let mut world = world();
+        let selector = SettingSelector::cycle::<TestSettings>("mode");
+
+        selector.step(&mut world, -1);
+        assert_eq!(world.resource::<TestSettings>().mode, TestMode::C);
+        assert_eq!(
+            selector.display(&world),
+            Some(("C".to_string(), false, false)),
+        );
+
+        selector.step(&mut world, 1);
+        assert_eq!(world.resource::<TestSettings>().mode, TestMode::A);
+        selector.step(&mut world, 1);
+        assert_eq!(world.resource::<TestSettings>().mode, TestMode::B);
+    }
+
+    #[test]
+    fn test_invalid_path() {
+        let mut world = world();
+        let selector =
+            SettingSelector::float::<TestSettings>("volume[2]", 0.1, 0.0..=1.0, format_percent);
+
+        selector.step(&mut world, 1);
+        assert_eq!(world.resource::<TestSettings>().volume, [0.0; 2]);
+        assert_eq!(selector.display(&world), None);
+
+        // A path to a field of the wrong type is also rejected.
+        let selector = SettingSelector::toggle::<TestSettings>("mode");
+        selector.step(&mut world, 1);
+        assert_eq!(world.resource::<TestSettings>().mode, TestMode::A);
+        assert_eq!(selector.display(&world), None);
+    }
+}
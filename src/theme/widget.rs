@@ -3,6 +3,9 @@ use bevy::ecs::system::IntoObserverSystem;
 use crate::animation::backup::Backup;
 use crate::animation::offset::NodeOffset;
 use crate::prelude::*;
+use crate::theme::scroll::IsScrollable;
+use crate::theme::setting::decrement_setting;
+use crate::theme::setting::increment_setting;
 
 pub fn overlay(z: i32) -> impl Bundle {
     (
@@ -28,8 +31,10 @@ pub fn body(children: impl Bundle) -> impl Bundle {
         Node {
             display: Display::Block,
             padding: UiRect::all(Vw(3.5)),
+            overflow: Overflow::scroll_y(),
             ..Node::DEFAULT.full_size()
         },
+        IsScrollable,
         children,
     )
 }
@@ -125,7 +130,22 @@ where
     B: Bundle,
     I: Sync + IntoObserverSystem<E, B, M>,
 {
-    button_base(Vw(3.0), Vw(4.0), Vw(3.0), text, action)
+    (
+        button_base(Vw(3.0), Vw(4.0), Vw(3.0), text, action),
+        Focusable::Button,
+    )
+}
+
+pub fn medium_button<E, B, M, I>(text: impl Into<String>, action: I) -> impl Bundle
+where
+    E: Event,
+    B: Bundle,
+    I: Sync + IntoObserverSystem<E, B, M>,
+{
+    (
+        button_base(Vw(14.0), Vw(5.0), Vw(2.5), text, action),
+        Focusable::Button,
+    )
 }
 
 pub fn button<E, B, M, I>(text: impl Into<String>, action: I) -> impl Bundle
@@ -134,7 +154,10 @@ where
     B: Bundle,
     I: Sync + IntoObserverSystem<E, B, M>,
 {
-    button_base(Vw(30.0), Vw(7.0), Vw(3.0), text, action)
+    (
+        button_base(Vw(30.0), Vw(7.0), Vw(3.0), text, action),
+        Focusable::Button,
+    )
 }
 
 pub fn wide_button<E, B, M, I>(text: impl Into<String>, action: I) -> impl Bundle
@@ -143,7 +166,10 @@ where
     B: Bundle,
     I: Sync + IntoObserverSystem<E, B, M>,
 {
-    button_base(Vw(38.0), Vw(7.0), Vw(3.0), text, action)
+    (
+        button_base(Vw(38.0), Vw(7.0), Vw(3.0), text, action),
+        Focusable::Button,
+    )
 }
 
 pub fn big_button<E, B, M, I>(text: impl Into<String>, action: I) -> impl Bundle
@@ -152,7 +178,10 @@ where
     B: Bundle,
     I: Sync + IntoObserverSystem<E, B, M>,
 {
-    button_base(Vw(38.0), Vw(10.0), Vw(4.0), text, action)
+    (
+        button_base(Vw(38.0), Vw(10.0), Vw(4.0), text, action),
+        Focusable::Button,
+    )
 }
 
 fn button_base<E, B, M, I>(
@@ -230,14 +259,25 @@ where
             ..Node::ROW
         },
         marker,
+        Focusable::Selector,
         children![
-            (small_button("<", left_action), InteractionDisabled(false)),
+            (
+                button_base(Vw(3.0), Vw(4.0), Vw(3.0), "<", left_action),
+                InteractionDisabled(false),
+            ),
             stretch(children![label("")]),
-            (small_button(">", right_action), InteractionDisabled(false)),
+            (
+                button_base(Vw(3.0), Vw(4.0), Vw(3.0), ">", right_action),
+                InteractionDisabled(false),
+            ),
         ],
     )
 }
 
+pub fn setting_selector(setting: SettingSelector) -> impl Bundle {
+    selector(setting, decrement_setting, increment_setting)
+}
+
 pub fn loading_bar<S: State + Clone + PartialEq + Eq + Hash + Debug>() -> impl Bundle {
     (
         Name::new("LoadingBar"),